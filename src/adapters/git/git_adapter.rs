@@ -1,11 +1,23 @@
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, Utc};
+
 use crate::domain::{
-    adapters::{self, CheckoutStatus, CommitMsgStatus, GitResult, GitSystem},
-    errors::GitError,
-    models::path::{AbsolutePath, PathType},
+    adapters::{
+        self, BranchLocation, CheckoutStatus, CommitMsgStatus, GitOperation, GitResult, GitSystem,
+        MergeStrategy,
+    },
+    errors::{CommandError, GitError},
+    models::{
+        path::{AbsolutePath, PathType},
+        GitCommit,
+    },
 };
 
+// Unit & record separators are used as they won't appear within commit messages.
+const FIELD_SEPARATOR: char = '\u{1f}';
+const RECORD_SEPARATOR: char = '\u{1e}';
+
 pub struct Git<S: GitSystem> {
     pub git: S,
 }
@@ -18,7 +30,7 @@ impl<S: GitSystem> adapters::Git for Git<S> {
             .get_output()
             .map_err(|e| {
                 log::error!("Failed to get root directory: {}", e);
-                GitError::RootDirectory
+                command_error(e, GitError::RootDirectory)
             })?;
 
         log::info!("git root directory {}", dir);
@@ -31,186 +43,1678 @@ impl<S: GitSystem> adapters::Git for Git<S> {
     }
 
     fn repository_name(&self) -> Result<String, GitError> {
-        let repo_dir: String = self.root_directory()?.try_into().map_err(|e| {
-            log::error!("Failed to get repository name: {}", e);
-            GitError::RootDirectory
-        })?;
-
-        let repo = repo_dir.split('/').last().ok_or_else(|| {
-            log::error!("Failed to get repository name");
-            GitError::RootDirectory
+        // Linked worktrees share the common git dir so are treated as the same repository.
+        let common_dir: PathBuf = self.git_directory("--git-common-dir")?.into();
+
+        let repo = repository_name(&common_dir).ok_or_else(|| {
+            log::error!(
+                "Failed to get repository name from '{}'",
+                common_dir.display()
+            );
+            GitError::GitDirectory
         })?;
 
         log::info!("git repository name '{}'", repo);
 
-        Ok(repo.trim().into())
+        Ok(repo)
     }
 
     fn branch_name(&self) -> Result<String, GitError> {
-        let branch = self
+        // Older git versions without `--show-current` fail, so fallback to the symbolic ref.
+        let commands: [&[&str]; 2] = [
+            &["branch", "--show-current"],
+            &["symbolic-ref", "--quiet", "--short", "HEAD"],
+        ];
+
+        for args in commands {
+            let branch = self.probe(args).map_err(|e| {
+                log::error!("Failed to get current branch name: {}", e);
+                command_error(e, GitError::BranchName)
+            })?;
+
+            let branch = branch.trim();
+            if !branch.is_empty() {
+                log::info!("current git branch name '{}'", branch);
+                return Ok(branch.into());
+            }
+        }
+
+        // HEAD is detached from a branch i.e. mid rebase or bisect.
+        self.detached_branch_name()
+    }
+
+    fn checkout(&self, name: &str, status: CheckoutStatus) -> Result<(), GitError> {
+        log::info!("checkout '{:?}' branch", status);
+
+        let mut command = match &status {
+            CheckoutStatus::New { from: None } => self.git.command(&["checkout", "-b", name]),
+            CheckoutStatus::New { from: Some(from) } => {
+                self.git.command(&["checkout", "-b", name, from])
+            }
+            CheckoutStatus::Existing => self.git.command(&["checkout", name]),
+            CheckoutStatus::Track { upstream } => self
+                .git
+                .command(&["checkout", "-b", name, "--track", upstream]),
+        };
+
+        command.get_status().map_err(|e| {
+            log::error!("Failed to checkout branch: {}", e);
+            command_error(e, GitError::Checkout { name: name.into() })
+        })?;
+
+        Ok(())
+    }
+
+    fn branch_location(&self, name: &str) -> Result<Option<BranchLocation>, GitError> {
+        let refs = self
             .git
-            .command(&["branch", "--show-current"])
+            .command(&[
+                "for-each-ref",
+                "--format=%(refname)",
+                &format!("refs/heads/{name}"),
+                "refs/remotes",
+            ])
             .get_output()
             .map_err(|e| {
-                log::error!("Failed to get current branch name: {}", e);
-                GitError::BranchName
+                log::error!("Failed to get branch refs: {}", e);
+                command_error(e, GitError::Branches)
+            })?;
+
+        let location = branch_location(name, refs.lines().map(str::trim));
+
+        log::info!("branch '{}' location {:?}", name, location);
+
+        Ok(location)
+    }
+
+    fn rename_branch(&self, from: &str, to: &str) -> Result<(), GitError> {
+        log::info!("rename branch '{}' to '{}'", from, to);
+
+        self.git
+            .command(&["branch", "--move", from, to])
+            .get_status()
+            .map_err(|e| {
+                log::error!("Failed to rename branch: {}", e);
+                command_error(
+                    e,
+                    GitError::Rename {
+                        from: from.into(),
+                        to: to.into(),
+                    },
+                )
+            })?;
+
+        Ok(())
+    }
+
+    fn branches(&self) -> Result<Vec<String>, GitError> {
+        let branches = self
+            .git
+            .command(&["for-each-ref", "--format=%(refname:short)", "refs/heads"])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get branches: {}", e);
+                command_error(e, GitError::Branches)
+            })?;
+
+        let branches: Vec<String> = branches
+            .lines()
+            .map(|branch| branch.trim().to_string())
+            .filter(|branch| !branch.is_empty())
+            .collect();
+
+        log::info!("local branches {:?}", branches);
+
+        Ok(branches)
+    }
+
+    fn branch_renames(&self) -> Result<Vec<(String, String)>, GitError> {
+        // Renames are recorded against the reflog of the renamed branch, so walk all of them.
+        let entries = self
+            .git
+            .command(&["log", "--walk-reflogs", "--all", "--format=%gs"])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get reflog: {}", e);
+                command_error(e, GitError::Branches)
+            })?;
+
+        // Renaming the checked out branch is also recorded in the HEAD reflog.
+        let mut renames: Vec<(String, String)> = vec![];
+        for rename in entries.lines().filter_map(parse_rename) {
+            if !renames.contains(&rename) {
+                renames.push(rename);
+            }
+        }
+
+        log::info!("branch renames {:?}", renames);
+
+        Ok(renames)
+    }
+
+    fn merge(&self, branch: &str, message: &str, strategy: MergeStrategy) -> Result<(), GitError> {
+        log::info!("merge branch '{}' with strategy {:?}", branch, strategy);
+
+        let path = self.write_message_file(message)?;
+
+        let error = |e: anyhow::Error| {
+            log::error!("Failed to merge branch: {}", e);
+            let branch = branch.into();
+            command_error(
+                e,
+                match strategy {
+                    MergeStrategy::Merge => GitError::Merge { branch },
+                    // 'git merge --abort' has nothing to abort after a squash as no merge is in progress.
+                    MergeStrategy::Squash => GitError::Squash { branch },
+                },
+            )
+        };
+
+        match strategy {
+            MergeStrategy::Merge => self
+                .git
+                .command(&["merge", "--no-ff", "--file", &path, branch])
+                .get_status()
+                .map_err(error)?,
+            // Squashing only stages the changes so they're committed separately.
+            MergeStrategy::Squash => {
+                self.git
+                    .command(&["merge", "--squash", branch])
+                    .get_status()
+                    .map_err(error)?;
+
+                self.git
+                    .command(&["commit", "--file", &path])
+                    .get_status()
+                    .map_err(error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn delete_branch(&self, name: &str, force: bool) -> Result<(), GitError> {
+        log::info!("delete branch '{}' with force '{}'", name, force);
+
+        let mut args = vec!["branch", "--delete"];
+        if force {
+            args.push("--force");
+        }
+        args.push(name);
+
+        self.git.command(&args).get_status().map_err(|e| {
+            log::error!("Failed to delete branch: {}", e);
+            command_error(e, GitError::DeleteBranch { name: name.into() })
+        })?;
+
+        Ok(())
+    }
+
+    fn staged_files(&self) -> Result<Vec<String>, GitError> {
+        let files = self
+            .git
+            .command(&["diff", "--cached", "--name-only"])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get staged files: {}", e);
+                command_error(e, GitError::StagedFiles)
+            })?;
+
+        let files: Vec<String> = files
+            .lines()
+            .map(|file| file.trim().to_string())
+            .filter(|file| !file.is_empty())
+            .collect();
+
+        log::info!("staged files {:?}", files);
+
+        Ok(files)
+    }
+
+    fn stage_tracked(&self) -> Result<(), GitError> {
+        log::info!("staging all tracked changes");
+
+        self.git
+            .command(&["add", "--update"])
+            .get_status()
+            .map_err(|e| {
+                log::error!("Failed to stage tracked changes: {}", e);
+                command_error(e, GitError::Stage)
+            })?;
+
+        Ok(())
+    }
+
+    fn operation_in_progress(&self) -> Result<Option<GitOperation>, GitError> {
+        // Files git keeps within the git dir while an operation is in progress.
+        let markers = [
+            (GitOperation::Merge, "MERGE_HEAD"),
+            (GitOperation::Rebase, "rebase-merge"),
+            (GitOperation::Rebase, "rebase-apply"),
+            (GitOperation::CherryPick, "CHERRY_PICK_HEAD"),
+            (GitOperation::Revert, "REVERT_HEAD"),
+        ];
+
+        let mut args = vec!["rev-parse"];
+        for (_, marker) in &markers {
+            args.extend(["--git-path", marker]);
+        }
+
+        let paths = self.git.command(&args).get_output().map_err(|e| {
+            log::error!("Failed to get git marker paths: {}", e);
+            command_error(e, GitError::State)
+        })?;
+
+        let operation = markers
+            .into_iter()
+            .zip(paths.lines())
+            .find(|(_, path)| Path::new(path.trim()).exists())
+            .map(|((operation, _), _)| operation);
+
+        log::info!("git operation in progress {:?}", operation);
+
+        Ok(operation)
+    }
+
+    fn is_detached(&self) -> Result<bool, GitError> {
+        // Outputs nothing when HEAD is not a symbolic ref to a branch.
+        let head = self
+            .probe(&["symbolic-ref", "--quiet", "HEAD"])
+            .map_err(|e| {
+                log::error!("Failed to get HEAD reference: {}", e);
+                command_error(e, GitError::State)
+            })?;
+
+        Ok(head.trim().is_empty())
+    }
+
+    fn log(&self, range: &str, max_count: Option<usize>) -> Result<Vec<GitCommit>, GitError> {
+        log::info!("retrieve commits for range '{}'", range);
+
+        let max_count = max_count.map(|count| format!("--max-count={count}"));
+        let mut args = vec!["log", "--no-merges", "--format=%H%x1f%an%x1f%aI%x1f%B%x1e"];
+        args.extend(max_count.as_deref());
+        args.push(range);
+
+        let output = self.git.command(&args).get_output().map_err(|e| {
+            log::error!("Failed to get commits: {}", e);
+            command_error(
+                e,
+                GitError::Log {
+                    range: range.into(),
+                },
+            )
+        })?;
+
+        let commits = output
+            .split(RECORD_SEPARATOR)
+            .map(|record| record.trim_start_matches('\n'))
+            .filter(|record| !record.trim().is_empty())
+            .map(|record| {
+                parse_commit(record).ok_or_else(|| {
+                    log::error!("Failed to parse commit record '{}'", record);
+                    GitError::Log {
+                        range: range.into(),
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        log::info!("retrieved {} commits", commits.len());
+
+        Ok(commits)
+    }
+
+    fn is_ancestor(&self, rev: &str) -> Result<bool, GitError> {
+        let result = self
+            .git
+            .command(&["merge-base", "--is-ancestor", rev, "HEAD"])
+            .get_output();
+
+        // Exits with 1 when not an ancestor, any other failure is an unknown revision.
+        let is_ancestor = match result {
+            Ok(_) => true,
+            Err(e) if e.downcast_ref::<CommandError>().map(|c| c.code) == Some(Some(1)) => false,
+            Err(e) => {
+                log::error!("Failed to check ancestry: {}", e);
+                return Err(command_error(e, GitError::Revision { rev: rev.into() }));
+            }
+        };
+
+        log::info!("revision '{}' is ancestor of HEAD: {}", rev, is_ancestor);
+
+        Ok(is_ancestor)
+    }
+
+    fn merges(&self, range: &str) -> Result<Vec<String>, GitError> {
+        log::info!("retrieve merge commits for range '{}'", range);
+
+        let output = self
+            .git
+            .command(&["rev-list", "--merges", range])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get merge commits: {}", e);
+                command_error(
+                    e,
+                    GitError::Log {
+                        range: range.into(),
+                    },
+                )
+            })?;
+
+        Ok(output
+            .lines()
+            .map(str::trim)
+            .filter(|hash| !hash.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    fn show(&self, rev: &str) -> Result<GitCommit, GitError> {
+        log::info!("retrieve commit for revision '{}'", rev);
+
+        let record = self
+            .git
+            .command(&["log", "-1", "--format=%H%x1f%an%x1f%aI%x1f%B", rev, "--"])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get commit: {}", e);
+                command_error(e, GitError::Revision { rev: rev.into() })
+            })?;
+
+        parse_commit(record.trim_start()).ok_or_else(|| {
+            log::error!("Failed to parse commit for revision '{}'", rev);
+            GitError::Revision { rev: rev.into() }
+        })
+    }
+
+    fn amend_message(&self, message: &str) -> Result<(), GitError> {
+        log::info!("amend HEAD commit message");
+
+        let path = self.write_message_file(message)?;
+
+        self.git
+            .command(&[
+                "commit",
+                "--amend",
+                "--only",
+                "--allow-empty",
+                "--file",
+                &path,
+            ])
+            .get_status()
+            .map_err(|e| {
+                log::error!("Failed to amend commit message: {}", e);
+                command_error(e, GitError::Reword { rev: "HEAD".into() })
+            })?;
+
+        Ok(())
+    }
+
+    fn reword(&self, messages: &[(String, String)]) -> Result<(), GitError> {
+        let Some((oldest, _)) = messages.last() else {
+            return Ok(());
+        };
+
+        log::info!("reword {} commits from '{}'", messages.len(), oldest);
+
+        let error = |e: anyhow::Error| {
+            log::error!("Failed to reword commits: {}", e);
+            command_error(
+                e,
+                GitError::Reword {
+                    rev: oldest.clone(),
+                },
+            )
+        };
+
+        // Each 'amend!' commit replaces its target's message once autosquashed.
+        for (hash, message) in messages {
+            let path = self.write_message_file(&format!("amend! {hash}\n\n{message}"))?;
+
+            self.git
+                .command(&[
+                    "commit",
+                    "--only",
+                    "--allow-empty",
+                    "--no-verify",
+                    "--file",
+                    &path,
+                ])
+                .get_status()
+                .map_err(error)?;
+        }
+
+        let parent = format!("{oldest}^");
+        let has_parent = !self
+            .probe(&["rev-parse", "--quiet", "--verify", &parent])
+            .map_err(error)?
+            .trim()
+            .is_empty();
+
+        let mut args = vec![
+            "-c",
+            "sequence.editor=true",
+            "rebase",
+            "--interactive",
+            "--autosquash",
+            "--autostash",
+        ];
+        match has_parent {
+            true => args.push(&parent),
+            false => args.push("--root"),
+        }
+
+        self.git.command(&args).get_status().map_err(error)?;
+
+        Ok(())
+    }
+
+    fn merge_base(&self, base: &str) -> Result<String, GitError> {
+        let hash = self
+            .git
+            .command(&["merge-base", "HEAD", base])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get merge base: {}", e);
+                command_error(e, GitError::MergeBase { base: base.into() })
+            })?;
+
+        let hash = hash.trim();
+        if hash.is_empty() {
+            return Err(GitError::MergeBase { base: base.into() });
+        }
+
+        log::info!("merge base with '{}' is '{}'", base, hash);
+
+        Ok(hash.into())
+    }
+
+    fn default_branch(&self) -> Result<String, GitError> {
+        // Fails when there's no remote HEAD i.e. no 'origin' remote.
+        let remote_head = self
+            .probe(&[
+                "symbolic-ref",
+                "--quiet",
+                "--short",
+                "refs/remotes/origin/HEAD",
+            ])
+            .map_err(|e| {
+                log::error!("Failed to get default branch: {}", e);
+                command_error(e, GitError::State)
             })?;
 
-        log::info!("current git branch name '{}'", branch);
+        let branch = match remote_head.trim() {
+            "" => "main".to_string(),
+            branch => branch.to_string(),
+        };
+
+        log::info!("default branch '{}'", branch);
 
         Ok(branch)
     }
 
-    fn checkout(&self, name: &str, status: CheckoutStatus) -> Result<(), GitError> {
-        log::info!("checkout '{:?}' branch", status);
+    fn tags(&self) -> Result<Vec<String>, GitError> {
+        let tags = self
+            .git
+            .command(&["tag", "--list", "--merged", "HEAD", "--sort=-v:refname"])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get tags: {}", e);
+                command_error(e, GitError::Tags)
+            })?;
+
+        let tags: Vec<String> = tags
+            .lines()
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+
+        log::info!("tags reachable from HEAD {:?}", tags);
+
+        Ok(tags)
+    }
+
+    fn create_tag(&self, name: &str, message: &str) -> Result<(), GitError> {
+        log::info!("create annotated tag '{}'", name);
+
+        self.git
+            .command(&["tag", "--annotate", name, "--message", message])
+            .get_status()
+            .map_err(|e| {
+                log::error!("Failed to create tag: {}", e);
+                command_error(e, GitError::Tag { name: name.into() })
+            })?;
+
+        Ok(())
+    }
+
+    fn template_file_path(&self) -> Result<PathBuf, GitError> {
+        // Template file and stored in the .git directory to avoid users having to adding to their .gitignore
+        // In future maybe we could make our own .git-kit dir to house config / templates along with this.
+        // `.git` is a file within linked worktrees & submodules so resolve the actual git dir.
+        let path: PathBuf = self.git_directory("--git-dir")?.into();
+
+        let path = path.join("GIT_KIT_COMMIT_TEMPLATE");
+
+        Ok(path)
+    }
+
+    fn commit_with_template(
+        &self,
+        template: &Path,
+        completed: CommitMsgStatus,
+    ) -> Result<(), GitError> {
+        log::info!("commit template with CommitMsgStatus: '{:?}'", completed);
+
+        if !template.is_file() {
+            return Err(GitError::Validation {
+                message: "Invalid template provided".into(),
+            });
+        }
+
+        let template = template
+            .as_os_str()
+            .to_str()
+            .ok_or_else(|| GitError::Validation {
+                message: "Failed to convert path to str".into(),
+            })?;
+
+        let mut args = vec!["commit", "--template", template];
+
+        // Pre-cautionary measure encase 'message' is provided but still matches template exactly.
+        // Otherwise git will just abort the commit if theres no difference / change from the template.
+        if completed == CommitMsgStatus::Completed {
+            log::info!("allowing an empty message on commit");
+            args.push("--allow-empty-message");
+        }
+
+        self.git.command(&args).get_status().map_err(|e| {
+            log::error!("Failed to commit template: {}", e);
+            command_error(e, GitError::Commit)
+        })?;
+
+        Ok(())
+    }
+}
+
+impl<S: GitSystem> Git<S> {
+    /// Output of a command where git exiting unsuccessfully is an expected answer
+    /// i.e. `symbolic-ref --quiet HEAD` on a detached HEAD, treated as no output.
+    fn probe(&self, args: &[&str]) -> anyhow::Result<String> {
+        match self.git.command(args).get_output() {
+            Err(e) if e.is::<CommandError>() => {
+                log::info!("git probe {:?} unsuccessful: {}", args, e);
+                Ok(String::new())
+            }
+            output => output,
+        }
+    }
+
+    /// Resolve a git directory via `rev-parse` i.e. `--git-dir` or `--git-common-dir`.
+    fn git_directory(&self, option: &str) -> Result<AbsolutePath, GitError> {
+        let dir = self
+            .git
+            .command(&["rev-parse", option])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get git directory: {}", e);
+                command_error(e, GitError::GitDirectory)
+            })?;
+
+        log::info!("git directory '{}' for '{}'", dir.trim(), option);
+
+        AbsolutePath::try_from(dir, PathType::Directory).map_err(|e| {
+            log::error!("Expected git directory: {}", e);
+            GitError::GitDirectory
+        })
+    }
+
+    /// Resolve the branch being rebased when HEAD is detached, otherwise error with the reason.
+    fn detached_branch_name(&self) -> Result<String, GitError> {
+        let paths = self
+            .git
+            .command(&[
+                "rev-parse",
+                "--git-path",
+                "rebase-merge/head-name",
+                "--git-path",
+                "rebase-apply/head-name",
+                "--git-path",
+                "BISECT_START",
+            ])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get git state paths: {}", e);
+                command_error(e, GitError::BranchName)
+            })?;
+
+        let paths: Vec<&Path> = paths.lines().map(|path| Path::new(path.trim())).collect();
+        let (head_names, bisect) = paths.split_at(paths.len().min(2));
+
+        for path in head_names {
+            let Ok(head_name) = std::fs::read_to_string(path) else {
+                continue;
+            };
+
+            // Rebasing a detached HEAD records 'detached HEAD' rather than a branch ref.
+            if let Some(branch) = head_name.trim().strip_prefix("refs/heads/") {
+                log::info!("branch '{}' is being rebased", branch);
+                return Ok(branch.into());
+            }
+        }
+
+        if bisect.iter().any(|path| path.exists()) {
+            return Err(GitError::Bisecting);
+        }
+
+        Err(GitError::DetachedHead)
+    }
+
+    fn write_message_file(&self, message: &str) -> Result<String, GitError> {
+        use adapters::Git as _;
+
+        let path = self.template_file_path()?;
+
+        std::fs::write(&path, message).map_err(|e| {
+            log::error!("Failed to write commit message file: {}", e);
+            GitError::Validation {
+                message: "Failed attempting to write commit message file".into(),
+            }
+        })?;
+
+        path.to_str()
+            .map(String::from)
+            .ok_or_else(|| GitError::Validation {
+                message: "Failed to convert path to str".into(),
+            })
+    }
+}
+
+/// Find a branch amongst full ref names i.e. `refs/heads/feat` or `refs/remotes/origin/feat`.
+pub(super) fn branch_location<'a, I>(name: &str, refs: I) -> Option<BranchLocation>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut upstreams = vec![];
+
+    for refname in refs {
+        if refname.strip_prefix("refs/heads/") == Some(name) {
+            return Some(BranchLocation::Local);
+        }
+
+        let Some(upstream) = refname.strip_prefix("refs/remotes/") else {
+            continue;
+        };
+
+        if let Some((remote, branch)) = upstream.split_once('/') {
+            if branch == name {
+                upstreams.push((remote == "origin", upstream.to_string()));
+            }
+        }
+    }
+
+    // Prefer 'origin' when several remotes have the branch.
+    upstreams.sort_by_key(|(origin, _)| !origin);
+    upstreams
+        .into_iter()
+        .next()
+        .map(|(_, upstream)| BranchLocation::Remote { upstream })
+}
+
+/// Keep the reason git gave for failing along with the expected error.
+fn command_error(error: anyhow::Error, expected: GitError) -> GitError {
+    match error.downcast::<CommandError>() {
+        Ok(command) => GitError::Command {
+            message: expected.to_string(),
+            command,
+        },
+        Err(_) => expected,
+    }
+}
+
+/// Repository name from the common git dir which is `<repo>/.git` for a regular repository,
+/// `<super>/.git/modules/<name>` for a submodule or `<repo>.git` when bare.
+pub(super) fn repository_name(common_dir: &Path) -> Option<String> {
+    let dir = match common_dir.file_name()? == ".git" {
+        true => common_dir.parent()?,
+        false => common_dir,
+    };
+
+    let name = dir.file_name()?.to_str()?;
+    let name = name.strip_suffix(".git").unwrap_or(name).trim();
+
+    (!name.is_empty()).then(|| name.into())
+}
+
+/// Parse a reflog subject such as `Branch: renamed refs/heads/old to refs/heads/new`,
+/// libgit2 based tools record the same message in lowercase.
+pub(super) fn parse_rename(subject: &str) -> Option<(String, String)> {
+    let subject = subject.trim();
+    let (from, to) = subject
+        .strip_prefix("Branch: renamed refs/heads/")
+        .or_else(|| subject.strip_prefix("branch: renamed refs/heads/"))?
+        .split_once(" to refs/heads/")?;
+
+    Some((from.into(), to.into()))
+}
+
+fn parse_commit(record: &str) -> Option<GitCommit> {
+    let mut fields = record.splitn(4, FIELD_SEPARATOR);
+
+    let hash = fields.next()?.trim().to_string();
+    let author = fields.next()?.trim().to_string();
+    let date = DateTime::parse_from_rfc3339(fields.next()?.trim())
+        .ok()?
+        .with_timezone(&Utc);
+    let message = fields.next()?.trim().to_string();
+
+    Some(GitCommit {
+        hash,
+        author,
+        date,
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Ok;
+
+    use crate::{adapters::git::GitCommand, domain::adapters::Git as _};
+
+    use super::*;
+
+    #[test]
+    fn root_repository_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["rev-parse", "--show-toplevel"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok(valid_dir_path().display().to_string()),
+                    }
+                },
+            },
+        };
+
+        let result = git.root_directory().unwrap();
+        assert_eq!(result, valid_dir_path().try_into().unwrap());
+    }
+
+    #[test]
+    fn root_repository_errors_on_system_command_failure() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || anyhow::bail!("Whoops"),
+                },
+            },
+        };
+
+        let err = git.root_directory().unwrap_err();
+        assert!(matches!(err, GitError::RootDirectory));
+    }
+
+    #[test]
+    #[ignore] // ignoring for CI
+    fn repository_name_returns_this_repo_name() {
+        let git = Git { git: GitCommand };
+        let repo = git.repository_name().unwrap();
+        assert_eq!(repo, "git-kit");
+    }
+
+    #[test]
+    fn repository_name_errors_on_get_output_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || anyhow::bail!("Whoops"),
+                },
+            },
+        };
+
+        let err = git.repository_name().unwrap_err();
+        assert!(matches!(err, GitError::GitDirectory));
+    }
+
+    #[test]
+    fn repository_name_uses_git_common_dir() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["rev-parse", "--git-common-dir"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || {
+                            Ok(valid_dir_path().join(".git").display().to_string())
+                        },
+                    }
+                },
+            },
+        };
+
+        let repo = git.repository_name().unwrap();
+        let expected = valid_dir_path();
+        assert_eq!(repo, expected.file_name().unwrap().to_str().unwrap());
+    }
+
+    #[test]
+    fn repository_name_from_common_dir() {
+        let cases = [
+            ("/code/git-kit/.git", Some("git-kit")),
+            ("/code/super/.git/modules/git-kit", Some("git-kit")),
+            ("/code/git-kit.git", Some("git-kit")),
+            ("/", None),
+        ];
+
+        for (common_dir, expected) in cases {
+            let actual = repository_name(Path::new(common_dir));
+            assert_eq!(actual.as_deref(), expected, "{common_dir}");
+        }
+    }
+
+    #[test]
+    fn template_file_path_is_within_git_dir() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["rev-parse", "--git-dir"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || {
+                            Ok(valid_dir_path().join(".git").display().to_string())
+                        },
+                    }
+                },
+            },
+        };
+
+        let path = git.template_file_path().unwrap();
+        assert_eq!(
+            path,
+            dunce::canonicalize(valid_dir_path().join(".git"))
+                .unwrap()
+                .join("GIT_KIT_COMMIT_TEMPLATE")
+        );
+    }
+
+    #[test]
+    fn branch_name_add_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["branch", "--show-current"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("my_branch".into()),
+                    }
+                },
+            },
+        };
+
+        let branch_name = git.branch_name().unwrap();
+        assert_eq!(branch_name, "my_branch");
+    }
+
+    #[test]
+    fn branch_name_trims_output() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("my_branch\n".into()),
+                },
+            },
+        };
+
+        let branch_name = git.branch_name().unwrap();
+        assert_eq!(branch_name, "my_branch");
+    }
+
+    #[test]
+    fn branch_name_falls_back_to_symbolic_ref() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: match args {
+                        ["branch", "--show-current"] => || Ok("".into()),
+                        ["symbolic-ref", "--quiet", "--short", "HEAD"] => {
+                            || Ok("my_branch\n".into())
+                        }
+                        _ => panic!("Unexpected git command {args:?}"),
+                    },
+                },
+            },
+        };
+
+        let branch_name = git.branch_name().unwrap();
+        assert_eq!(branch_name, "my_branch");
+    }
+
+    #[test]
+    fn branch_name_resolves_branch_being_rebased() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: match args[0] {
+                        "rev-parse" => || {
+                            let head_name = rebase_head_name_path();
+                            std::fs::write(&head_name, "refs/heads/feat/rebasing\n")?;
+
+                            Ok(format!(
+                                "{}\n/does/not/exist\n/does/not/exist\n",
+                                head_name.display()
+                            ))
+                        },
+                        _ => || Ok("".into()),
+                    },
+                },
+            },
+        };
+
+        let branch_name = git.branch_name();
+        std::fs::remove_file(rebase_head_name_path()).unwrap();
+
+        assert_eq!(branch_name.unwrap(), "feat/rebasing");
+    }
+
+    /// Unique to this test process so parallel runs don't share the file.
+    fn rebase_head_name_path() -> PathBuf {
+        std::env::temp_dir().join(format!("git-kit-rebase-head-name-{}", std::process::id()))
+    }
+
+    #[test]
+    fn branch_name_errors_when_bisecting() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: match args[0] {
+                        // 'BISECT_START' marker resolves to an existing path.
+                        "rev-parse" => || {
+                            Ok(format!(
+                                "/does/not/exist\n/does/not/exist\n{}\n",
+                                valid_file_path().display()
+                            ))
+                        },
+                        _ => || Ok("".into()),
+                    },
+                },
+            },
+        };
+
+        let err = git.branch_name().unwrap_err();
+        assert!(matches!(err, GitError::Bisecting));
+    }
+
+    #[test]
+    fn branch_name_errors_when_detached() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: match args[0] {
+                        "rev-parse" => {
+                            || Ok("/does/not/exist\n/does/not/exist\n/does/not/exist\n".into())
+                        }
+                        _ => || Ok("".into()),
+                    },
+                },
+            },
+        };
+
+        let err = git.branch_name().unwrap_err();
+        assert!(matches!(err, GitError::DetachedHead));
+    }
+
+    #[test]
+    fn branch_name_error_on_get_output_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || anyhow::bail!("Whoops"),
+                },
+            },
+        };
+
+        let err = git.branch_name().unwrap_err();
+        assert!(matches!(err, GitError::BranchName));
+    }
+
+    #[test]
+    fn staged_files_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["diff", "--cached", "--name-only"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("src/lib.rs\nREADME.md\n".into()),
+                    }
+                },
+            },
+        };
+
+        let files = git.staged_files().unwrap();
+        assert_eq!(files, ["src/lib.rs", "README.md"]);
+    }
+
+    #[test]
+    fn staged_files_errors_on_get_output_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || anyhow::bail!("Whoops"),
+                },
+            },
+        };
+
+        let err = git.staged_files().unwrap_err();
+        assert!(matches!(err, GitError::StagedFiles));
+    }
+
+    #[test]
+    fn stage_tracked_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["add", "--update"]);
+
+                    GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    }
+                },
+            },
+        };
+
+        git.stage_tracked().unwrap();
+    }
+
+    #[test]
+    fn stage_tracked_errors_on_get_status_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || anyhow::bail!("Whoops"),
+                    get_output_result: || panic!("Should not be called!"),
+                },
+            },
+        };
+
+        let err = git.stage_tracked().unwrap_err();
+        assert!(matches!(err, GitError::Stage));
+    }
+
+    #[test]
+    fn operation_in_progress_with_existing_marker_returns_operation() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args[0], "rev-parse");
+                    assert_eq!(args[1..3], ["--git-path", "MERGE_HEAD"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        // 'rebase-merge' marker resolves to an existing path.
+                        get_output_result: || {
+                            Ok(format!(
+                                "/does/not/exist\n{}\n/does/not/exist\n",
+                                valid_dir_path().display()
+                            ))
+                        },
+                    }
+                },
+            },
+        };
+
+        let operation = git.operation_in_progress().unwrap();
+        assert_eq!(operation, Some(GitOperation::Rebase));
+    }
+
+    #[test]
+    fn operation_in_progress_without_markers_returns_none() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("/does/not/exist\n/does/not/exist\n".into()),
+                },
+            },
+        };
+
+        let operation = git.operation_in_progress().unwrap();
+        assert_eq!(operation, None);
+    }
+
+    #[test]
+    fn is_detached_with_symbolic_ref_output_is_attached() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["symbolic-ref", "--quiet", "HEAD"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("refs/heads/main\n".into()),
+                    }
+                },
+            },
+        };
+
+        assert!(!git.is_detached().unwrap());
+    }
+
+    #[test]
+    fn is_detached_without_symbolic_ref_output_is_detached() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("".into()),
+                },
+            },
+        };
+
+        assert!(git.is_detached().unwrap());
+    }
+
+    #[test]
+    fn log_adds_expected_git_options_and_parses_commits() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        [
+                            "log",
+                            "--no-merges",
+                            "--format=%H%x1f%an%x1f%aI%x1f%B%x1e",
+                            "v1.0.0..HEAD"
+                        ]
+                    );
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || {
+                            Ok([
+                                "abc1234def\u{1f}Jane\u{1f}2022-11-01T10:00:00+13:00\u{1f}feat: subject\n\nbody\n\u{1e}",
+                                "\n0987654fed\u{1f}John\u{1f}2022-10-01T10:00:00Z\u{1f}fix: other\n\u{1e}\n",
+                            ]
+                            .concat())
+                        },
+                    }
+                },
+            },
+        };
+
+        let commits = git.log("v1.0.0..HEAD", None).unwrap();
+
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].hash, "abc1234def");
+        assert_eq!(commits[0].short_hash(), "abc1234");
+        assert_eq!(commits[0].author, "Jane");
+        assert_eq!(commits[0].message, "feat: subject\n\nbody");
+        assert_eq!(commits[0].subject(), "feat: subject");
+        assert_eq!(
+            commits[0].date,
+            DateTime::parse_from_rfc3339("2022-10-31T21:00:00Z").unwrap()
+        );
+        assert_eq!(commits[1].hash, "0987654fed");
+        assert_eq!(commits[1].message, "fix: other");
+    }
+
+    #[test]
+    fn log_limits_commits_with_max_count() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        [
+                            "log",
+                            "--no-merges",
+                            "--format=%H%x1f%an%x1f%aI%x1f%B%x1e",
+                            "--max-count=5",
+                            "HEAD"
+                        ]
+                    );
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("".into()),
+                    }
+                },
+            },
+        };
+
+        let commits = git.log("HEAD", Some(5)).unwrap();
+
+        assert!(commits.is_empty());
+    }
+
+    #[test]
+    fn log_errors_on_get_output_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || anyhow::bail!("Whoops"),
+                },
+            },
+        };
+
+        let err = git.log("HEAD", None).unwrap_err();
+        assert!(matches!(err, GitError::Log { range } if range == "HEAD"));
+    }
+
+    #[test]
+    fn is_ancestor_false_when_merge_base_exits_with_one() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["merge-base", "--is-ancestor", "other", "HEAD"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || {
+                            Err(CommandError {
+                                code: Some(1),
+                                stderr: "".into(),
+                                ..command_failure()
+                            }
+                            .into())
+                        },
+                    }
+                },
+            },
+        };
+
+        assert!(!git.is_ancestor("other").unwrap());
+    }
+
+    #[test]
+    fn is_ancestor_errors_on_unknown_revision() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Err(command_failure().into()),
+                },
+            },
+        };
+
+        let err = git.is_ancestor("other").unwrap_err();
+
+        assert!(matches!(err, GitError::Command { .. }));
+    }
+
+    #[test]
+    fn merges_lists_merge_commit_hashes() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["rev-list", "--merges", "abc1234..HEAD"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("def5678\n0123abc\n".into()),
+                    }
+                },
+            },
+        };
+
+        assert_eq!(git.merges("abc1234..HEAD").unwrap(), ["def5678", "0123abc"]);
+    }
+
+    #[test]
+    fn merge_base_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["merge-base", "HEAD", "main"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("abc1234\n".into()),
+                    }
+                },
+            },
+        };
+
+        assert_eq!(git.merge_base("main").unwrap(), "abc1234");
+    }
+
+    #[test]
+    fn merge_base_without_output_errors() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("".into()),
+                },
+            },
+        };
+
+        let err = git.merge_base("main").unwrap_err();
+        assert!(matches!(err, GitError::MergeBase { base } if base == "main"));
+    }
+
+    #[test]
+    fn default_branch_uses_remote_head() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        [
+                            "symbolic-ref",
+                            "--quiet",
+                            "--short",
+                            "refs/remotes/origin/HEAD"
+                        ]
+                    );
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("origin/develop\n".into()),
+                    }
+                },
+            },
+        };
+
+        assert_eq!(git.default_branch().unwrap(), "origin/develop");
+    }
+
+    #[test]
+    fn default_branch_without_remote_head_is_main() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("".into()),
+                },
+            },
+        };
+
+        assert_eq!(git.default_branch().unwrap(), "main");
+    }
+
+    #[test]
+    fn tags_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        ["tag", "--list", "--merged", "HEAD", "--sort=-v:refname"]
+                    );
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("v1.1.0\nv1.0.0\n".into()),
+                    }
+                },
+            },
+        };
+
+        let tags = git.tags().unwrap();
+        assert_eq!(tags, ["v1.1.0", "v1.0.0"]);
+    }
+
+    #[test]
+    fn create_tag_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        ["tag", "--annotate", "v1.0.0", "--message", "Release v1.0.0"]
+                    );
 
-        let mut command = match status {
-            CheckoutStatus::New => self.git.command(&["checkout", "-b", name]),
-            CheckoutStatus::Existing => self.git.command(&["checkout", name]),
+                    GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    }
+                },
+            },
         };
 
-        command.get_status().map_err(|e| {
-            log::error!("Failed to checkout branch: {}", e);
-            GitError::Checkout { name: name.into() }
-        })?;
-
-        Ok(())
+        git.create_tag("v1.0.0", "Release v1.0.0").unwrap();
     }
 
-    fn template_file_path(&self) -> Result<PathBuf, GitError> {
-        // Template file and stored in the .git directory to avoid users having to adding to their .gitignore
-        // In future maybe we could make our own .git-kit dir to house config / templates along with this.
-        let path: PathBuf = self.root_directory()?.into();
-
-        let path = path.join(".git").join("GIT_KIT_COMMIT_TEMPLATE");
+    #[test]
+    fn create_tag_errors_on_get_status_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || anyhow::bail!("Whoops"),
+                    get_output_result: || panic!("Should not be called!"),
+                },
+            },
+        };
 
-        Ok(path)
+        let err = git.create_tag("v1.0.0", "message").unwrap_err();
+        assert!(matches!(err, GitError::Tag { name } if name == "v1.0.0"));
     }
 
-    fn commit_with_template(
-        &self,
-        template: &Path,
-        completed: CommitMsgStatus,
-    ) -> Result<(), GitError> {
-        log::info!("commit template with CommitMsgStatus: '{:?}'", completed);
+    #[test]
+    fn merge_creates_merge_commit_with_message_file() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| match args {
+                    ["rev-parse", "--git-dir"] => GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok(std::env::temp_dir().display().to_string()),
+                    },
+                    ["merge", "--no-ff", "--file", path, "feat"] => {
+                        assert!(path.ends_with("GIT_KIT_COMMIT_TEMPLATE"));
+
+                        GitResultMock {
+                            get_status_result: || Ok(()),
+                            get_output_result: || panic!("Should not be called!"),
+                        }
+                    }
+                    args => panic!("Unexpected command {args:?}"),
+                },
+            },
+        };
 
-        if !template.is_file() {
-            return Err(GitError::Validation {
-                message: "Invalid template provided".into(),
-            });
-        }
+        git.merge("feat", "Merge 'feat'", MergeStrategy::Merge)
+            .unwrap();
+    }
 
-        let template = template
-            .as_os_str()
-            .to_str()
-            .ok_or_else(|| GitError::Validation {
-                message: "Failed to convert path to str".into(),
-            })?;
+    #[test]
+    fn merge_squash_commits_staged_changes_with_message_file() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| match args {
+                    ["rev-parse", "--git-dir"] => GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok(std::env::temp_dir().display().to_string()),
+                    },
+                    ["merge", "--squash", "feat"] | ["commit", "--file", _] => GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    },
+                    args => panic!("Unexpected command {args:?}"),
+                },
+            },
+        };
 
-        let mut args = vec!["commit", "--template", template];
+        git.merge("feat", "Squash 'feat'", MergeStrategy::Squash)
+            .unwrap();
+    }
 
-        // Pre-cautionary measure encase 'message' is provided but still matches template exactly.
-        // Otherwise git will just abort the commit if theres no difference / change from the template.
-        if completed == CommitMsgStatus::Completed {
-            log::info!("allowing an empty message on commit");
-            args.push("--allow-empty-message");
-        }
+    #[test]
+    fn merge_errors_with_branch_on_get_status_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| match args {
+                    ["rev-parse", "--git-dir"] => GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok(std::env::temp_dir().display().to_string()),
+                    },
+                    _ => GitResultMock {
+                        get_status_result: || anyhow::bail!("Whoops"),
+                        get_output_result: || panic!("Should not be called!"),
+                    },
+                },
+            },
+        };
 
-        self.git.command(&args).get_status().map_err(|e| {
-            log::error!("Failed to commit template: {}", e);
-            GitError::Commit
-        })?;
+        let err = git
+            .merge("feat", "message", MergeStrategy::Merge)
+            .unwrap_err();
+        assert!(matches!(err, GitError::Merge { branch } if branch == "feat"));
 
-        Ok(())
+        let err = git
+            .merge("feat", "message", MergeStrategy::Squash)
+            .unwrap_err();
+        assert!(matches!(err, GitError::Squash { branch } if branch == "feat"));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use anyhow::Ok;
+    #[test]
+    fn delete_branch_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["branch", "--delete", "feat"]);
 
-    use crate::{adapters::git::GitCommand, domain::adapters::Git as _};
+                    GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    }
+                },
+            },
+        };
 
-    use super::*;
+        git.delete_branch("feat", false).unwrap();
+    }
 
     #[test]
-    fn root_repository_adds_expected_git_options() {
+    fn delete_branch_with_force_adds_force_option() {
         let git = Git {
             git: GitSystemMock {
                 result: |args| {
-                    assert_eq!(args, ["rev-parse", "--show-toplevel"]);
+                    assert_eq!(args, ["branch", "--delete", "--force", "feat"]);
 
                     GitResultMock {
-                        get_status_result: || panic!("Should not be called!"),
-                        get_output_result: || Ok(valid_dir_path().display().to_string()),
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
                     }
                 },
             },
         };
 
-        let result = git.root_directory().unwrap();
-        assert_eq!(result, valid_dir_path().try_into().unwrap());
+        git.delete_branch("feat", true).unwrap();
     }
 
     #[test]
-    fn root_repository_errors_on_system_command_failure() {
+    fn rename_branch_adds_expected_git_options() {
         let git = Git {
             git: GitSystemMock {
-                result: |_| GitResultMock {
-                    get_status_result: || panic!("Should not be called!"),
-                    get_output_result: || anyhow::bail!("Whoops"),
+                result: |args| {
+                    assert_eq!(args, ["branch", "--move", "old", "new"]);
+
+                    GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    }
                 },
             },
         };
 
-        let err = git.root_directory().unwrap_err();
-        assert!(matches!(err, GitError::RootDirectory));
+        git.rename_branch("old", "new").unwrap();
     }
 
     #[test]
-    #[ignore] // ignoring for CI
-    fn repository_name_returns_this_repo_name() {
-        let git = Git { git: GitCommand };
-        let repo = git.repository_name().unwrap();
-        assert_eq!(repo, "git-kit");
+    fn rename_branch_errors_on_get_status_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || anyhow::bail!("Whoops"),
+                    get_output_result: || panic!("Should not be called!"),
+                },
+            },
+        };
+
+        let err = git.rename_branch("old", "new").unwrap_err();
+        assert!(matches!(err, GitError::Rename { from, to } if from == "old" && to == "new"));
     }
 
     #[test]
-    fn repository_name_errors_on_get_output_system_command() {
+    fn branches_adds_expected_git_options() {
         let git = Git {
             git: GitSystemMock {
-                result: |_| GitResultMock {
-                    get_status_result: || panic!("Should not be called!"),
-                    get_output_result: || anyhow::bail!("Whoops"),
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        ["for-each-ref", "--format=%(refname:short)", "refs/heads"]
+                    );
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("feat/a\nmain\n".into()),
+                    }
                 },
             },
         };
 
-        let err = git.repository_name().unwrap_err();
-        assert!(matches!(err, GitError::RootDirectory));
+        let branches = git.branches().unwrap();
+        assert_eq!(branches, ["feat/a", "main"]);
     }
 
     #[test]
-    fn branch_name_add_expected_git_options() {
+    fn branch_renames_parses_rename_reflog_entries() {
         let git = Git {
             git: GitSystemMock {
                 result: |args| {
-                    assert_eq!(args, ["branch", "--show-current"]);
+                    assert_eq!(args, ["log", "--walk-reflogs", "--all", "--format=%gs"]);
 
                     GitResultMock {
                         get_status_result: || panic!("Should not be called!"),
-                        get_output_result: || Ok("my_branch".into()),
+                        get_output_result: || {
+                            Ok([
+                                "Branch: renamed refs/heads/feat/old to refs/heads/feat/new",
+                                "checkout: moving from main to feat/old",
+                                "branch: Created from HEAD",
+                                "Branch: renamed refs/heads/a to refs/heads/b",
+                                "Branch: renamed refs/heads/a to refs/heads/b",
+                            ]
+                            .join("\n"))
+                        },
                     }
                 },
             },
         };
 
-        let branch_name = git.branch_name().unwrap();
-        assert_eq!(branch_name, "my_branch");
+        let renames = git.branch_renames().unwrap();
+        assert_eq!(
+            renames,
+            [
+                ("feat/old".to_string(), "feat/new".to_string()),
+                ("a".to_string(), "b".to_string())
+            ]
+        );
     }
 
     #[test]
-    fn branch_name_error_on_get_output_system_command() {
+    fn branch_renames_errors_on_get_output_system_command() {
         let git = Git {
             git: GitSystemMock {
                 result: |_| GitResultMock {
@@ -220,8 +1724,8 @@ mod tests {
             },
         };
 
-        let err = git.branch_name().unwrap_err();
-        assert!(matches!(err, GitError::BranchName));
+        let err = git.branch_renames().unwrap_err();
+        assert!(matches!(err, GitError::Branches));
     }
 
     #[test]
@@ -331,7 +1835,8 @@ mod tests {
             },
         };
 
-        git.checkout("my-branch", CheckoutStatus::New).unwrap()
+        git.checkout("my-branch", CheckoutStatus::New { from: None })
+            .unwrap()
     }
 
     #[test]
@@ -351,6 +1856,109 @@ mod tests {
         git.checkout("my-branch", CheckoutStatus::Existing).unwrap()
     }
 
+    #[test]
+    fn checkout_new_branch_from_start_point_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["checkout", "-b", "my-branch", "develop"]);
+                    GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    }
+                },
+            },
+        };
+
+        let status = CheckoutStatus::New {
+            from: Some("develop".into()),
+        };
+        git.checkout("my-branch", status).unwrap()
+    }
+
+    #[test]
+    fn checkout_tracking_branch_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        ["checkout", "-b", "my-branch", "--track", "origin/my-branch"]
+                    );
+                    GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    }
+                },
+            },
+        };
+
+        let status = CheckoutStatus::Track {
+            upstream: "origin/my-branch".into(),
+        };
+        git.checkout("my-branch", status).unwrap()
+    }
+
+    #[test]
+    fn branch_location_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        [
+                            "for-each-ref",
+                            "--format=%(refname)",
+                            "refs/heads/feat",
+                            "refs/remotes"
+                        ]
+                    );
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("refs/remotes/origin/feat\n".into()),
+                    }
+                },
+            },
+        };
+
+        let location = git.branch_location("feat").unwrap();
+        assert_eq!(
+            location,
+            Some(BranchLocation::Remote {
+                upstream: "origin/feat".into()
+            })
+        );
+    }
+
+    #[test]
+    fn branch_location_matches_full_branch_names() {
+        let cases: [(&[&str], Option<BranchLocation>); 4] = [
+            (
+                &["refs/heads/feat", "refs/remotes/origin/feat"],
+                Some(BranchLocation::Local),
+            ),
+            (
+                &["refs/heads/feat/nested", "refs/remotes/origin/feat/nested"],
+                None,
+            ),
+            (
+                &["refs/remotes/fork/feat", "refs/remotes/origin/feat"],
+                Some(BranchLocation::Remote {
+                    upstream: "origin/feat".into(),
+                }),
+            ),
+            (&["refs/remotes/origin/HEAD"], None),
+        ];
+
+        for (refs, expected) in cases {
+            assert_eq!(
+                branch_location("feat", refs.iter().copied()),
+                expected,
+                "{refs:?}"
+            );
+        }
+    }
+
     #[test]
     fn checkout_errors_on_get_status_system_command_fail() {
         let git = Git {
@@ -362,10 +1970,69 @@ mod tests {
             },
         };
 
-        let err = git.checkout("name", CheckoutStatus::New).unwrap_err();
+        let err = git
+            .checkout("name", CheckoutStatus::New { from: None })
+            .unwrap_err();
         assert!(matches!(err, GitError::Checkout { name } if name == "name"));
     }
 
+    #[test]
+    fn checkout_errors_with_git_reason_on_command_failure() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || Err(command_failure().into()),
+                    get_output_result: || panic!("Should not be called!"),
+                },
+            },
+        };
+
+        let err = git
+            .checkout("feat", CheckoutStatus::New { from: None })
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to checkout branch \"feat\", fatal: a branch named 'feat' already exists"
+        );
+        assert!(matches!(err, GitError::Command { command, .. } if command == command_failure()));
+    }
+
+    #[test]
+    fn is_detached_on_symbolic_ref_command_failure() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Err(command_failure().into()),
+                },
+            },
+        };
+
+        assert!(git.is_detached().unwrap());
+    }
+
+    #[test]
+    fn command_error_without_output_describes_command() {
+        let error = CommandError {
+            stderr: "".into(),
+            ..command_failure()
+        };
+
+        assert_eq!(
+            error.reason(),
+            "'git checkout -b feat' exited with code 128"
+        );
+    }
+
+    fn command_failure() -> CommandError {
+        CommandError {
+            args: vec!["checkout".into(), "-b".into(), "feat".into()],
+            code: Some(128),
+            stdout: "".into(),
+            stderr: "fatal: a branch named 'feat' already exists".into(),
+        }
+    }
+
     #[derive(Debug, Clone)]
     pub struct GitResultMock {
         pub get_status_result: fn() -> anyhow::Result<()>,
@@ -1,5 +1,9 @@
 mod git_adapter;
+#[cfg(feature = "native-git")]
+mod native_adapter;
 mod system_adapter;
 
 pub use git_adapter::Git;
+#[cfg(feature = "native-git")]
+pub use native_adapter::NativeGit;
 pub use system_adapter::GitCommand;
@@ -0,0 +1,723 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{TimeZone, Utc};
+use git2::{BranchType, Repository, RepositoryState, RevparseMode, Revwalk, Sort};
+
+use crate::domain::{
+    adapters::{
+        self, BranchLocation, CheckoutStatus, CommitMsgStatus, GitOperation, GitSystem,
+        MergeStrategy,
+    },
+    errors::GitError,
+    models::{
+        path::{AbsolutePath, PathType},
+        GitCommit,
+    },
+};
+
+use super::git_adapter::{branch_location, parse_rename, repository_name, Git};
+
+/// In-process git implementation for read operations, avoiding a process per call.
+/// Anything writing to the repository or opening an editor still shells out via `Git`.
+pub struct NativeGit<S: GitSystem> {
+    repo: Repository,
+    shell: Git<S>,
+}
+
+impl<S: GitSystem> NativeGit<S> {
+    pub fn new(repo: Repository, shell: Git<S>) -> NativeGit<S> {
+        NativeGit { repo, shell }
+    }
+
+    /// Open the repository for the current directory respecting `GIT_DIR` like the git cli.
+    pub fn open(shell: Git<S>) -> Result<NativeGit<S>, GitError> {
+        let repo = Repository::open_from_env().map_err(|e| {
+            log::error!("Failed to open git repository: {}", e);
+            GitError::RootDirectory
+        })?;
+
+        Ok(NativeGit::new(repo, shell))
+    }
+
+    fn commit(&self, commit: &git2::Commit) -> GitCommit {
+        GitCommit {
+            hash: commit.id().to_string(),
+            author: commit.author().name().unwrap_or_default().trim().into(),
+            date: Utc
+                .timestamp_opt(commit.author().when().seconds(), 0)
+                .single()
+                .unwrap_or_default(),
+            message: commit.message().unwrap_or_default().trim().into(),
+        }
+    }
+
+    /// Walk commits within a revision range most recent first.
+    /// Symmetric difference ranges (i.e. `a...b`) are `None` so left to the git cli.
+    fn revwalk(&self, range: &str) -> Result<Option<Revwalk<'_>>, git2::Error> {
+        let spec = self.repo.revparse(range)?;
+
+        if spec.mode().contains(RevparseMode::MERGE_BASE) {
+            return Ok(None);
+        }
+
+        let mut walk = self.repo.revwalk()?;
+        walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)?;
+
+        match (spec.from(), spec.to()) {
+            (Some(from), Some(to)) => {
+                walk.push(to.id())?;
+                walk.hide(from.id())?;
+            }
+            (Some(rev), None) | (None, Some(rev)) => walk.push(rev.id())?,
+            (None, None) => return Err(git2::Error::from_str("empty revision range")),
+        }
+
+        Ok(Some(walk))
+    }
+
+    /// Branch HEAD symbolically refers to, `None` when detached.
+    fn head_branch(&self) -> Result<Option<String>, git2::Error> {
+        let head = self.repo.find_reference("HEAD")?;
+
+        let branch = head
+            .symbolic_target()
+            .and_then(|target| target.strip_prefix("refs/heads/"))
+            .map(String::from);
+
+        Ok(branch)
+    }
+}
+
+impl<S: GitSystem> adapters::Git for NativeGit<S> {
+    fn root_directory(&self) -> Result<AbsolutePath, GitError> {
+        let dir = self.repo.workdir().ok_or_else(|| {
+            log::error!("Failed to get root directory of a bare repository");
+            GitError::RootDirectory
+        })?;
+
+        log::info!("git root directory {}", dir.display());
+
+        AbsolutePath::try_from(dir.display().to_string(), PathType::Directory).map_err(|e| {
+            log::error!("Expected git root directory: {}", e);
+            GitError::RootDirectory
+        })
+    }
+
+    fn repository_name(&self) -> Result<String, GitError> {
+        // Linked worktrees share the common git dir so are treated as the same repository.
+        let common_dir = self.repo.commondir();
+
+        let repo = repository_name(common_dir).ok_or_else(|| {
+            log::error!(
+                "Failed to get repository name from '{}'",
+                common_dir.display()
+            );
+            GitError::GitDirectory
+        })?;
+
+        log::info!("git repository name '{}'", repo);
+
+        Ok(repo)
+    }
+
+    fn branch_name(&self) -> Result<String, GitError> {
+        let branch = self.head_branch().map_err(|e| {
+            log::error!("Failed to get current branch name: {}", e);
+            GitError::BranchName
+        })?;
+
+        match branch {
+            Some(branch) => {
+                log::info!("current git branch name '{}'", branch);
+                Ok(branch)
+            }
+            // Resolving a rebase or bisect in progress is left to the git cli.
+            None => self.shell.branch_name(),
+        }
+    }
+
+    fn checkout(&self, name: &str, status: CheckoutStatus) -> Result<(), GitError> {
+        self.shell.checkout(name, status)
+    }
+
+    fn rename_branch(&self, from: &str, to: &str) -> Result<(), GitError> {
+        self.shell.rename_branch(from, to)
+    }
+
+    fn merge(&self, branch: &str, message: &str, strategy: MergeStrategy) -> Result<(), GitError> {
+        self.shell.merge(branch, message, strategy)
+    }
+
+    fn delete_branch(&self, name: &str, force: bool) -> Result<(), GitError> {
+        self.shell.delete_branch(name, force)
+    }
+
+    fn branch_location(&self, name: &str) -> Result<Option<BranchLocation>, GitError> {
+        let refs = self
+            .repo
+            .references()
+            .map(|refs| {
+                refs.filter_map(|reference| reference.ok())
+                    .filter_map(|reference| reference.name().map(String::from))
+                    .collect::<Vec<_>>()
+            })
+            .map_err(|e| {
+                log::error!("Failed to get branch refs: {}", e);
+                GitError::Branches
+            })?;
+
+        let location = branch_location(name, refs.iter().map(String::as_str));
+
+        log::info!("branch '{}' location {:?}", name, location);
+
+        Ok(location)
+    }
+
+    fn branches(&self) -> Result<Vec<String>, GitError> {
+        let branches = self
+            .repo
+            .branches(Some(BranchType::Local))
+            .and_then(|branches| {
+                branches
+                    .map(|branch| {
+                        let (branch, _) = branch?;
+                        Ok(branch.name()?.unwrap_or_default().to_string())
+                    })
+                    .collect::<Result<Vec<_>, git2::Error>>()
+            })
+            .map_err(|e| {
+                log::error!("Failed to get branches: {}", e);
+                GitError::Branches
+            })?;
+
+        log::info!("local branches {:?}", branches);
+
+        Ok(branches)
+    }
+
+    fn branch_renames(&self) -> Result<Vec<(String, String)>, GitError> {
+        let mut refs = vec!["HEAD".to_string()];
+        refs.extend(
+            self.branches()?
+                .into_iter()
+                .map(|branch| format!("refs/heads/{branch}")),
+        );
+
+        let mut renames: Vec<(String, String)> = vec![];
+        for name in refs {
+            let reflog = self.repo.reflog(&name).map_err(|e| {
+                log::error!("Failed to get reflog for '{}': {}", name, e);
+                GitError::Branches
+            })?;
+
+            for rename in reflog
+                .iter()
+                .filter_map(|entry| entry.message().and_then(parse_rename))
+            {
+                if !renames.contains(&rename) {
+                    renames.push(rename);
+                }
+            }
+        }
+
+        log::info!("branch renames {:?}", renames);
+
+        Ok(renames)
+    }
+
+    fn staged_files(&self) -> Result<Vec<String>, GitError> {
+        // No HEAD tree before the first commit so everything in the index is staged.
+        let head = self
+            .repo
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_tree().ok());
+
+        let diff = self
+            .repo
+            .diff_tree_to_index(head.as_ref(), None, None)
+            .map_err(|e| {
+                log::error!("Failed to get staged files: {}", e);
+                GitError::StagedFiles
+            })?;
+
+        let files: Vec<String> = diff
+            .deltas()
+            .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+            .map(|path| path.display().to_string())
+            .collect();
+
+        log::info!("staged files {:?}", files);
+
+        Ok(files)
+    }
+
+    fn stage_tracked(&self) -> Result<(), GitError> {
+        self.shell.stage_tracked()
+    }
+
+    fn operation_in_progress(&self) -> Result<Option<GitOperation>, GitError> {
+        let operation = match self.repo.state() {
+            RepositoryState::Merge => Some(GitOperation::Merge),
+            RepositoryState::Rebase
+            | RepositoryState::RebaseInteractive
+            | RepositoryState::RebaseMerge
+            | RepositoryState::ApplyMailboxOrRebase => Some(GitOperation::Rebase),
+            RepositoryState::CherryPick | RepositoryState::CherryPickSequence => {
+                Some(GitOperation::CherryPick)
+            }
+            RepositoryState::Revert | RepositoryState::RevertSequence => Some(GitOperation::Revert),
+            _ => None,
+        };
+
+        log::info!("git operation in progress {:?}", operation);
+
+        Ok(operation)
+    }
+
+    fn is_detached(&self) -> Result<bool, GitError> {
+        let branch = self.head_branch().map_err(|e| {
+            log::error!("Failed to get HEAD reference: {}", e);
+            GitError::State
+        })?;
+
+        Ok(branch.is_none())
+    }
+
+    fn log(&self, range: &str, max_count: Option<usize>) -> Result<Vec<GitCommit>, GitError> {
+        log::info!("retrieve commits for range '{}'", range);
+
+        let error = |e: git2::Error| {
+            log::error!("Failed to get commits: {}", e);
+            GitError::Log {
+                range: range.into(),
+            }
+        };
+
+        let Some(walk) = self.revwalk(range).map_err(error)? else {
+            return self.shell.log(range, max_count);
+        };
+
+        let mut commits = vec![];
+        for oid in walk {
+            if Some(commits.len()) == max_count {
+                break;
+            }
+
+            let commit = self.repo.find_commit(oid.map_err(error)?).map_err(error)?;
+
+            if commit.parent_count() <= 1 {
+                commits.push(self.commit(&commit));
+            }
+        }
+
+        log::info!("retrieved {} commits", commits.len());
+
+        Ok(commits)
+    }
+
+    fn is_ancestor(&self, rev: &str) -> Result<bool, GitError> {
+        let error = |e: git2::Error| {
+            log::error!("Failed to check ancestry: {}", e);
+            GitError::Revision { rev: rev.into() }
+        };
+
+        let commit = self
+            .repo
+            .revparse_single(rev)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(error)?
+            .id();
+        let head = self
+            .repo
+            .revparse_single("HEAD")
+            .and_then(|object| object.peel_to_commit())
+            .map_err(error)?
+            .id();
+
+        let is_ancestor =
+            commit == head || self.repo.graph_descendant_of(head, commit).map_err(error)?;
+
+        log::info!("revision '{}' is ancestor of HEAD: {}", rev, is_ancestor);
+
+        Ok(is_ancestor)
+    }
+
+    fn merges(&self, range: &str) -> Result<Vec<String>, GitError> {
+        log::info!("retrieve merge commits for range '{}'", range);
+
+        let error = |e: git2::Error| {
+            log::error!("Failed to get merge commits: {}", e);
+            GitError::Log {
+                range: range.into(),
+            }
+        };
+
+        let Some(walk) = self.revwalk(range).map_err(error)? else {
+            return self.shell.merges(range);
+        };
+
+        let mut merges = vec![];
+        for oid in walk {
+            let commit = self.repo.find_commit(oid.map_err(error)?).map_err(error)?;
+
+            if commit.parent_count() > 1 {
+                merges.push(commit.id().to_string());
+            }
+        }
+
+        Ok(merges)
+    }
+
+    fn show(&self, rev: &str) -> Result<GitCommit, GitError> {
+        log::info!("retrieve commit for revision '{}'", rev);
+
+        let commit = self
+            .repo
+            .revparse_single(rev)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|e| {
+                log::error!("Failed to get commit: {}", e);
+                GitError::Revision { rev: rev.into() }
+            })?;
+
+        Ok(self.commit(&commit))
+    }
+
+    fn amend_message(&self, message: &str) -> Result<(), GitError> {
+        self.shell.amend_message(message)
+    }
+
+    fn reword(&self, messages: &[(String, String)]) -> Result<(), GitError> {
+        self.shell.reword(messages)
+    }
+
+    fn merge_base(&self, base: &str) -> Result<String, GitError> {
+        let error = |e: git2::Error| {
+            log::error!("Failed to get merge base: {}", e);
+            GitError::MergeBase { base: base.into() }
+        };
+
+        let head = self
+            .repo
+            .revparse_single("HEAD")
+            .and_then(|object| object.peel_to_commit())
+            .map_err(error)?;
+        let other = self
+            .repo
+            .revparse_single(base)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(error)?;
+
+        let hash = self
+            .repo
+            .merge_base(head.id(), other.id())
+            .map_err(error)?
+            .to_string();
+
+        log::info!("merge base with '{}' is '{}'", base, hash);
+
+        Ok(hash)
+    }
+
+    fn default_branch(&self) -> Result<String, GitError> {
+        let branch = self
+            .repo
+            .find_reference("refs/remotes/origin/HEAD")
+            .ok()
+            .and_then(|head| {
+                head.symbolic_target()
+                    .and_then(|target| target.strip_prefix("refs/remotes/"))
+                    .map(String::from)
+            })
+            .unwrap_or_else(|| "main".into());
+
+        log::info!("default branch '{}'", branch);
+
+        Ok(branch)
+    }
+
+    fn tags(&self) -> Result<Vec<String>, GitError> {
+        // Version sorting is left to the git cli.
+        self.shell.tags()
+    }
+
+    fn create_tag(&self, name: &str, message: &str) -> Result<(), GitError> {
+        self.shell.create_tag(name, message)
+    }
+
+    fn template_file_path(&self) -> Result<PathBuf, GitError> {
+        let path = self.repo.path().join("GIT_KIT_COMMIT_TEMPLATE");
+
+        Ok(path)
+    }
+
+    fn commit_with_template(
+        &self,
+        template: &Path,
+        completed: CommitMsgStatus,
+    ) -> Result<(), GitError> {
+        self.shell.commit_with_template(template, completed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use git2::{RepositoryInitOptions, Signature};
+
+    use crate::{adapters::git::GitCommand, domain::adapters::Git as _};
+
+    use super::*;
+
+    #[test]
+    fn branch_name_returns_branch_head_refers_to() -> anyhow::Result<()> {
+        let git = native_git("branch-name")?;
+
+        assert_eq!(git.branch_name()?, "main");
+        assert!(!git.is_detached()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_detached_when_head_points_at_commit() -> anyhow::Result<()> {
+        let git = native_git("detached")?;
+        let hash = commit(&git.repo, "Initial")?;
+
+        git.repo.set_head_detached(hash)?;
+
+        assert!(git.is_detached()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn repository_name_is_repository_directory() -> anyhow::Result<()> {
+        let git = native_git("repository-name")?;
+
+        assert_eq!(git.repository_name()?, "git-kit-native-repository-name");
+
+        Ok(())
+    }
+
+    #[test]
+    fn branches_returns_local_branches() -> anyhow::Result<()> {
+        let git = native_git("branches")?;
+        let head = git.repo.find_commit(commit(&git.repo, "Initial")?)?;
+        git.repo.branch("feat/a", &head, false)?;
+
+        let mut branches = git.branches()?;
+        branches.sort();
+
+        assert_eq!(branches, ["feat/a", "main"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn branch_renames_reads_rename_reflog_entries() -> anyhow::Result<()> {
+        let git = native_git("renames")?;
+        let head = git.repo.find_commit(commit(&git.repo, "Initial")?)?;
+        let mut branch = git.repo.branch("feat/old", &head, false)?;
+        branch.rename("feat/new", false)?;
+
+        let renames = git.branch_renames()?;
+
+        assert_eq!(renames, [("feat/old".to_string(), "feat/new".to_string())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn log_returns_commits_in_range_most_recent_first() -> anyhow::Result<()> {
+        let git = native_git("log")?;
+        let first = commit(&git.repo, "First")?;
+        commit(&git.repo, "Second")?;
+        commit(&git.repo, "Third\n\nWith a body")?;
+
+        let all = git.log("HEAD", None)?;
+        let range = git.log(&format!("{first}..HEAD"), None)?;
+        let latest = git.log("HEAD", Some(1))?;
+
+        assert_eq!(all.len(), 3);
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[0].message, "Third\n\nWith a body");
+        let messages: Vec<&str> = range.iter().map(|c| c.message.as_str()).collect();
+        assert_eq!(messages, ["Third\n\nWith a body", "Second"]);
+        assert_eq!(range[0].author, "git-kit");
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_ancestor_of_head_only_for_reachable_commits() -> anyhow::Result<()> {
+        let git = native_git("is-ancestor")?;
+        let first = commit(&git.repo, "First")?;
+        commit(&git.repo, "Second")?;
+
+        let head = git.repo.head()?.peel_to_commit()?;
+        git.repo.set_head_detached(first)?;
+        let other = commit(&git.repo, "Other")?;
+        git.repo.set_head_detached(head.id())?;
+
+        assert!(git.is_ancestor("HEAD")?);
+        assert!(git.is_ancestor(&first.to_string())?);
+        assert!(!git.is_ancestor(&other.to_string())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn merges_returns_merge_commits_in_range() -> anyhow::Result<()> {
+        let git = native_git("merges")?;
+        let first = commit(&git.repo, "First")?;
+        let second = commit(&git.repo, "Second")?;
+
+        let signature = Signature::now("git-kit", "git-kit@test")?;
+        let tree = git.repo.find_commit(second)?.tree()?;
+        let parents = [
+            &git.repo.find_commit(second)?,
+            &git.repo.find_commit(first)?,
+        ];
+        let merge = git.repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            "Merge",
+            &tree,
+            &parents,
+        )?;
+        commit(&git.repo, "Third")?;
+
+        assert_eq!(git.merges(&format!("{first}..HEAD"))?, [merge.to_string()]);
+        assert!(git.merges(&format!("{merge}..HEAD"))?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn show_returns_commit_for_revision() -> anyhow::Result<()> {
+        let git = native_git("show")?;
+        let first = commit(&git.repo, "First")?;
+        commit(&git.repo, "Second")?;
+
+        let commit = git.show("HEAD~1")?;
+
+        assert_eq!(commit.hash, first.to_string());
+        assert_eq!(commit.message, "First");
+
+        Ok(())
+    }
+
+    #[test]
+    fn show_errors_on_unknown_revision() -> anyhow::Result<()> {
+        let git = native_git("show-unknown")?;
+
+        let err = git.show("does-not-exist").unwrap_err();
+
+        assert!(matches!(err, GitError::Revision { rev } if rev == "does-not-exist"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_base_returns_common_ancestor() -> anyhow::Result<()> {
+        let git = native_git("merge-base")?;
+        let base = commit(&git.repo, "First")?;
+        git.repo
+            .branch("base", &git.repo.find_commit(base)?, false)?;
+        commit(&git.repo, "Second")?;
+
+        assert_eq!(git.merge_base("base")?, base.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn staged_files_returns_index_changes() -> anyhow::Result<()> {
+        let git = native_git("staged")?;
+        commit(&git.repo, "Initial")?;
+
+        let root = git.repo.workdir().unwrap().to_path_buf();
+        std::fs::write(root.join("staged.txt"), "staged")?;
+        std::fs::write(root.join("unstaged.txt"), "unstaged")?;
+
+        let mut index = git.repo.index()?;
+        index.add_path(Path::new("staged.txt"))?;
+        index.write()?;
+
+        assert_eq!(git.staged_files()?, ["staged.txt"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn operation_in_progress_none_on_clean_repository() -> anyhow::Result<()> {
+        let git = native_git("operation")?;
+
+        assert_eq!(git.operation_in_progress()?, None);
+
+        Ok(())
+    }
+
+    /// Repository within a temporary directory removed once dropped.
+    struct TestRepo {
+        git: NativeGit<GitCommand>,
+        dir: PathBuf,
+    }
+
+    impl std::ops::Deref for TestRepo {
+        type Target = NativeGit<GitCommand>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.git
+        }
+    }
+
+    impl Drop for TestRepo {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+            // Only succeeds once the last repository of the process is removed.
+            if let Some(parent) = self.dir.parent() {
+                let _ = std::fs::remove_dir(parent);
+            }
+        }
+    }
+
+    fn native_git(name: &str) -> anyhow::Result<TestRepo> {
+        // Nested within a directory per process so the repository name stays the same.
+        let dir = std::env::temp_dir()
+            .join(format!("git-kit-native-{}", std::process::id()))
+            .join(format!("git-kit-native-{name}"));
+
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+
+        let repo = Repository::init_opts(&dir, RepositoryInitOptions::new().initial_head("main"))?;
+
+        Ok(TestRepo {
+            git: NativeGit::new(repo, Git { git: GitCommand }),
+            dir,
+        })
+    }
+
+    fn commit(repo: &Repository, message: &str) -> anyhow::Result<git2::Oid> {
+        let signature = Signature::now("git-kit", "git-kit@test")?;
+        let tree = repo.find_tree(repo.index()?.write_tree()?)?;
+
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+        let hash = repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents,
+        )?;
+
+        Ok(hash)
+    }
+}
@@ -1,27 +1,52 @@
-use crate::domain::adapters::{GitResult, GitSystem};
-use std::process::Command;
+use crate::domain::{
+    adapters::{GitResult, GitSystem},
+    errors::CommandError,
+};
+use std::process::{Command, ExitStatus};
 
 pub struct GitCommand;
 
 /// Implementation of GitResult wrapper for system command result
 impl GitResult for Command {
     fn get_status(&mut self) -> anyhow::Result<()> {
+        // Output is left attached to the terminal so editors, progress & git's own errors are shown as is.
         let status = self.status().map_err(|e| anyhow::anyhow!(e))?;
 
         if !status.success() {
-            anyhow::bail!("Failed to process system command.")
-        } else {
-            Ok(())
+            anyhow::bail!(command_error(self, status, "", ""));
         }
+
+        Ok(())
     }
 
     fn get_output(&mut self) -> anyhow::Result<String> {
-        let stdout: String = self
-            .output()
-            .map(|out| String::from_utf8_lossy(&out.stdout).into())
-            .map_err(|e| anyhow::anyhow!(e))?;
+        let output = self.output().map_err(|e| anyhow::anyhow!(e))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
 
-        Ok(stdout)
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!(command_error(self, output.status, &stdout, &stderr));
+        }
+
+        Ok(stdout.into())
+    }
+}
+
+fn command_error(
+    command: &Command,
+    status: ExitStatus,
+    stdout: &str,
+    stderr: &str,
+) -> CommandError {
+    CommandError {
+        args: command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into())
+            .collect(),
+        code: status.code(),
+        stdout: stdout.trim().into(),
+        stderr: stderr.trim().into(),
     }
 }
 
@@ -34,3 +59,56 @@ impl GitSystem for GitCommand {
         comm
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_output_returns_stdout() {
+        let output = GitCommand.command(&["--version"]).get_output().unwrap();
+
+        assert!(output.starts_with("git version"));
+    }
+
+    #[test]
+    fn get_output_errors_with_command_details_on_failure() {
+        let error = GitCommand
+            .command(&[
+                "rev-parse",
+                "--verify",
+                "--end-of-options",
+                "does-not-exist",
+            ])
+            .get_output()
+            .unwrap_err()
+            .downcast::<CommandError>()
+            .unwrap();
+
+        assert_eq!(
+            error.args,
+            [
+                "rev-parse",
+                "--verify",
+                "--end-of-options",
+                "does-not-exist"
+            ]
+        );
+        assert_eq!(error.code, Some(128));
+        assert!(error.stderr.contains("fatal"), "{}", error.stderr);
+    }
+
+    #[test]
+    fn get_status_errors_with_exit_code_on_failure() {
+        let error = GitCommand
+            .command(&["checkout", "--quiet", "does-not-exist-branch"])
+            .get_status()
+            .unwrap_err()
+            .downcast::<CommandError>()
+            .unwrap();
+
+        assert_ne!(error.code, Some(0));
+        // Git already reported the failure to the terminal so it isn't repeated.
+        assert!(error.stderr.is_empty());
+    }
+}
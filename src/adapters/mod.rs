@@ -4,4 +4,6 @@ mod store;
 
 pub use git::Git;
 pub use git::GitCommand;
+#[cfg(feature = "native-git")]
+pub use git::NativeGit;
 pub use store::sqlite;
@@ -10,7 +10,7 @@ use crate::{
 use colored::Colorize;
 use inquire::{
     ui::{Attributes, Color, RenderConfig, StyleSheet, Styled},
-    InquireError, Select, Text,
+    Confirm, Editor, InquireError, MultiSelect, Select, Text,
 };
 
 impl<T> Display for SelectItem<T> {
@@ -28,7 +28,13 @@ pub struct Prompt;
 
 impl Prompt {
     fn filter<T>(input: &str, option: &SelectItem<T>) -> bool {
-        option.name.to_lowercase().contains(&input.to_lowercase())
+        let input = input.to_lowercase();
+
+        fuzzy_match(&input, &option.name.to_lowercase())
+            || option
+                .description
+                .as_ref()
+                .is_some_and(|description| fuzzy_match(&input, &description.to_lowercase()))
     }
 
     fn get_render_config() -> RenderConfig {
@@ -78,6 +84,56 @@ impl Prompter for Prompt {
 
         Ok(result.none_if_empty())
     }
+
+    fn editor(
+        &self,
+        name: &str,
+        default: Option<String>,
+    ) -> Result<Option<String>, UserInputError> {
+        let result = Editor::new(&format!("{name}:"))
+            .with_predefined_text(&default.unwrap_or("".into()))
+            .with_render_config(Self::get_render_config())
+            .prompt_skippable()
+            .map_err(|e| into_domain_error(name, e))?;
+
+        Ok(result.none_if_empty())
+    }
+
+    fn confirm(&self, name: &str, default: bool) -> Result<bool, UserInputError> {
+        let result = Confirm::new(&format!("{name}?"))
+            .with_default(default)
+            .with_render_config(Self::get_render_config())
+            .prompt()
+            .map_err(|e| into_domain_error(name, e))?;
+
+        Ok(result)
+    }
+
+    fn multi_select<T>(
+        &self,
+        name: &str,
+        options: Vec<SelectItem<T>>,
+    ) -> Result<Vec<SelectItem<T>>, UserInputError> {
+        let len = options.len();
+        let selected = MultiSelect::new(&format!("{name}:"), options)
+            .with_page_size(len)
+            .with_filter(&|input, option, _, _| Self::filter(input, option))
+            .with_render_config(Self::get_render_config())
+            .prompt()
+            .map_err(|e| into_domain_error(name, e))?;
+
+        Ok(selected)
+    }
+}
+
+/// Every character of the input appears in order within the text i.e. "fl" matches "feat/login".
+fn fuzzy_match(input: &str, text: &str) -> bool {
+    let mut text = text.chars();
+
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|c| text.any(|t| t == c))
 }
 
 fn into_domain_error(name: &str, error: InquireError) -> UserInputError {
@@ -96,6 +152,7 @@ fn into_domain_error(name: &str, error: InquireError) -> UserInputError {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::Prompt;
     use crate::domain::adapters::prompt::SelectItem;
@@ -125,4 +182,30 @@ mod tests {
         assert_eq!(false, Prompt::filter("value", &item));
         assert_eq!(false, Prompt::filter("Contains value in the string", &item));
     }
+
+    #[test]
+    fn filter_fuzzy_matches_name_in_order() {
+        let item = SelectItem {
+            name: "feat/login-form".to_string(),
+            description: None,
+            value: "value",
+        };
+
+        assert!(Prompt::filter("login", &item));
+        assert!(Prompt::filter("FLF", &item));
+        assert!(Prompt::filter("feat form", &item));
+        assert!(!Prompt::filter("form login", &item));
+    }
+
+    #[test]
+    fn filter_fuzzy_matches_description() {
+        let item = SelectItem {
+            name: "feat/login-form".to_string(),
+            description: Some("ABC-123 · parser".to_string()),
+            value: "value",
+        };
+
+        assert!(Prompt::filter("abc123", &item));
+        assert!(!Prompt::filter("abc124", &item));
+    }
 }
@@ -1,9 +1,11 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use rusqlite::{types::Type, Row};
 
 use crate::domain::{
     errors::PersistError,
-    models::{path::AbsolutePath, Branch, Config, ConfigKey},
+    models::{path::AbsolutePath, Branch, CommitHistory, CommitInputs, Config, ConfigKey, Ticket},
 };
 
 impl PersistError {
@@ -14,6 +16,13 @@ impl PersistError {
         PersistError::into("config", message, error)
     }
 
+    pub fn into_history_error<S>(message: S, error: rusqlite::Error) -> PersistError
+    where
+        S: Into<String>,
+    {
+        PersistError::into("history", message, error)
+    }
+
     pub fn into_branch_error<S>(message: S, error: rusqlite::Error) -> PersistError
     where
         S: Into<String>,
@@ -103,15 +112,126 @@ impl<'a> TryFrom<&Row<'a>> for Branch {
             })?
             .with_timezone(&Utc);
 
+        let tickets: Vec<Ticket> = match value.get::<_, Option<String>>(6)? {
+            Some(tickets) => serde_yaml::from_str(&tickets).map_err(|e| {
+                log::error!("Corrupted data failed to convert branch tickets, {}", e);
+                rusqlite::Error::InvalidColumnType(
+                    6,
+                    "Failed to convert branch tickets".into(),
+                    Type::Text,
+                )
+            })?,
+            None => vec![],
+        };
+
+        let last_used = optional_date(value, 7)?;
+        let archived = optional_date(value, 8)?;
+
+        let data = match value.get::<_, Option<Vec<u8>>>(2)? {
+            Some(data) if !data.is_empty() => serde_yaml::from_slice(&data).map_err(|e| {
+                log::error!("Corrupted data failed to convert branch context, {}", e);
+                rusqlite::Error::InvalidColumnType(
+                    2,
+                    "Failed to convert branch context".into(),
+                    Type::Blob,
+                )
+            })?,
+            _ => BTreeMap::new(),
+        };
+
         let branch = Branch {
             name: value.get(0)?,
+            repo: value.get(9)?,
             ticket: value.get(1)?,
-            data: value.get(2)?,
+            data,
             created,
             link: value.get(4)?,
             scope: value.get(5)?,
+            tickets,
+            last_used,
+            archived,
         };
 
         Ok(branch)
     }
 }
+
+/// Nullable RFC 3339 date column.
+fn optional_date(value: &Row, index: usize) -> Result<Option<DateTime<Utc>>, rusqlite::Error> {
+    value
+        .get::<_, Option<String>>(index)?
+        .map(|date| DateTime::parse_from_rfc3339(&date))
+        .transpose()
+        .map(|date| date.map(|date| date.with_timezone(&Utc)))
+        .map_err(|e| {
+            log::error!("Corrupted data failed to convert to datetime, {}", e);
+            rusqlite::Error::InvalidColumnType(
+                index,
+                "Failed to convert string to DateTime".into(),
+                Type::Text,
+            )
+        })
+}
+
+impl Branch {
+    /// Key/value context serialized for the `data` column, empty context is stored as null.
+    pub fn data_bytes(&self) -> Result<Option<Vec<u8>>, PersistError> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+
+        serde_yaml::to_string(&self.data)
+            .map(|data| Some(data.into_bytes()))
+            .map_err(|e| PersistError::Validation {
+                name: "branch".into(),
+                source: e.into(),
+            })
+    }
+}
+
+impl<'a> TryFrom<&Row<'a>> for CommitHistory {
+    type Error = rusqlite::Error;
+
+    fn try_from(value: &Row) -> Result<Self, Self::Error> {
+        let inputs: CommitInputs =
+            serde_yaml::from_str(&value.get::<_, String>(4)?).map_err(|e| {
+                log::error!("Corrupted data failed to convert commit inputs, {}", e);
+                rusqlite::Error::InvalidColumnType(
+                    4,
+                    "Failed to convert commit inputs".into(),
+                    Type::Text,
+                )
+            })?;
+
+        let status = value.get::<_, String>(6)?.try_into().map_err(|e| {
+            log::error!("Corrupted data failed to convert to commit status, {}", e);
+            rusqlite::Error::InvalidColumnType(
+                6,
+                "Failed to convert to valid commit status".into(),
+                Type::Text,
+            )
+        })?;
+
+        let created = DateTime::parse_from_rfc3339(&value.get::<_, String>(7)?)
+            .map_err(|e| {
+                log::error!("Corrupted data failed to convert to datetime, {}", e);
+                rusqlite::Error::InvalidColumnType(
+                    7,
+                    "Failed to convert string to DateTime".into(),
+                    Type::Text,
+                )
+            })?
+            .with_timezone(&Utc);
+
+        Ok(CommitHistory {
+            id: value.get(0)?,
+            repo: value.get(1)?,
+            branch: value.get(2)?,
+            template: value.get(3)?,
+            inputs,
+            message: value.get(5)?,
+            status,
+            created,
+        })
+    }
+}
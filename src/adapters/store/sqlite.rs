@@ -1,10 +1,11 @@
 use anyhow::anyhow;
+use chrono::Utc;
 use rusqlite::{Connection, Transaction};
 
 use crate::domain::{
     self,
     errors::PersistError,
-    models::{Branch, Config, ConfigKey, ConfigStatus},
+    models::{Branch, CommitHistory, Config, ConfigKey, ConfigStatus},
 };
 
 pub struct Sqlite {
@@ -16,7 +17,7 @@ impl Sqlite {
         Sqlite { connection }
     }
 
-    pub fn transaction(&mut self) -> Result<Transaction, PersistError> {
+    pub fn transaction(&mut self) -> Result<Transaction<'_>, PersistError> {
         let transaction = self.connection.transaction().map_err(|e| {
             log::error!("Failed to open transaction for sqlite db: {}", &e);
             PersistError::Unknown(e.into())
@@ -34,16 +35,32 @@ impl domain::adapters::Store for Sqlite {
             branch.ticket
         );
 
+        let tickets = match branch.tickets.is_empty() {
+            true => None,
+            false => Some(serde_yaml::to_string(&branch.tickets).map_err(|e| {
+                PersistError::Validation {
+                    name: "branch".into(),
+                    source: e.into(),
+                }
+            })?),
+        };
+
+        let data = branch.data_bytes()?;
+
         self.connection
             .execute(
-                "REPLACE INTO branch (name, ticket, data, created, link, scope) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                "REPLACE INTO branch (name, ticket, data, created, link, scope, tickets, last_used, archived, repo) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
                 (
                     &branch.name,
                     &branch.ticket,
-                    &branch.data,
+                    data,
                     &branch.created.to_rfc3339(),
                     &branch.link,
-                    &branch.scope
+                    &branch.scope,
+                    tickets,
+                    branch.last_used.map(|date| date.to_rfc3339()),
+                    branch.archived.map(|date| date.to_rfc3339()),
+                    &branch.repo,
                 ),
             )
             .map_err(|e| PersistError::into_branch_error(format!("Failed to update branch '{}'", branch.name), e))?;
@@ -60,10 +77,10 @@ impl domain::adapters::Store for Sqlite {
             repo
         );
 
-        let branch = self
+        let mut branch = self
             .connection
             .query_row(
-                "SELECT name, ticket, data, created, link, scope FROM branch where name = ?",
+                "SELECT name, ticket, data, created, link, scope, tickets, last_used, archived, repo FROM branch where name = ?",
                 [name],
                 |row| Branch::try_from(row),
             )
@@ -71,9 +88,169 @@ impl domain::adapters::Store for Sqlite {
                 PersistError::into_branch_error("Failed to retrieve branch '{name}'", e)
             })?;
 
+        // Contexts stored before the repo was recorded pick it up when next persisted.
+        branch.repo.get_or_insert_with(|| repo.trim().into());
+
         Ok(branch)
     }
 
+    fn get_branches(&self, repo: &str) -> Result<Vec<Branch>, PersistError> {
+        let prefix = format!("{}-", repo.trim());
+
+        log::info!("retrieve branches for repo '{}'", repo);
+
+        let mut statement = self
+            .connection
+            .prepare(
+                "SELECT name, ticket, data, created, link, scope, tickets, last_used, archived, repo FROM branch WHERE repo = ?1 OR (repo IS NULL AND instr(name, ?2) = 1) ORDER BY created DESC",
+            )
+            .map_err(|e| PersistError::into_branch_error("Failed to retrieve branches", e))?;
+
+        // Contexts stored before the repo was recorded can only be matched by their name prefix.
+        let branches: Vec<_> = statement
+            .query_map([repo.trim(), &prefix], |row| Branch::try_from(row))
+            .map_err(|e| PersistError::into_branch_error("Failed to retrieve branches", e))?
+            .collect::<Result<_, _>>()
+            .map_err(|e| PersistError::into_branch_error("Failed to retrieve branches", e))?;
+
+        Ok(branches)
+    }
+
+    fn rename_branch(&mut self, from: &str, to: &str, repo: &str) -> Result<Branch, PersistError> {
+        let (from, to, repo) = (from.trim(), to.trim(), repo.trim());
+
+        log::info!("rename branch '{}' to '{}' for repo '{}'", from, to, repo);
+
+        let transaction = self.transaction()?;
+
+        // Branches without an explicit ticket default to the branch name so follow the rename.
+        let updated = transaction
+            .execute(
+                "UPDATE branch SET name = ?1, ticket = CASE WHEN trim(ticket) = ?3 THEN ?4 ELSE ticket END, repo = ?5 WHERE name = ?2",
+                (
+                    format!("{repo}-{to}"),
+                    format!("{repo}-{from}"),
+                    from,
+                    to,
+                    repo,
+                ),
+            )
+            .map_err(|e| {
+                PersistError::into_branch_error(format!("Failed to rename branch '{from}'"), e)
+            })?;
+
+        if updated == 0 {
+            return Err(PersistError::NotFound {
+                name: "branch".into(),
+            });
+        }
+
+        transaction
+            .execute(
+                "UPDATE history SET branch = ?1 WHERE repo = ?2 AND branch = ?3",
+                (to, repo, from),
+            )
+            .map_err(|e| {
+                PersistError::into_history_error(
+                    format!("Failed to move history for branch '{from}'"),
+                    e,
+                )
+            })?;
+
+        transaction.commit().map_err(|e| {
+            PersistError::into_branch_error("Failed to commit transaction to rename branch", e)
+        })?;
+
+        self.get_branch(to, repo)
+    }
+
+    fn archive_branch(&self, branch: &str, repo: &str) -> Result<Branch, PersistError> {
+        let name = format!("{}-{}", repo.trim(), branch.trim());
+
+        log::info!("archive branch '{}'", name);
+
+        let updated = self
+            .connection
+            .execute(
+                "UPDATE branch SET archived = ?1 WHERE name = ?2",
+                (Utc::now().to_rfc3339(), &name),
+            )
+            .map_err(|e| {
+                PersistError::into_branch_error(format!("Failed to archive branch '{name}'"), e)
+            })?;
+
+        if updated == 0 {
+            return Err(PersistError::NotFound {
+                name: "branch".into(),
+            });
+        }
+
+        self.get_branch(branch, repo)
+    }
+
+    fn persist_history(&self, history: &CommitHistory) -> Result<(), PersistError> {
+        log::info!(
+            "insert or update history '{}' for branch '{}' with status '{:?}'",
+            history.id,
+            history.branch,
+            history.status
+        );
+
+        let inputs =
+            serde_yaml::to_string(&history.inputs).map_err(|e| PersistError::Validation {
+                name: "history".into(),
+                source: e.into(),
+            })?;
+
+        self.connection
+            .execute(
+                "REPLACE INTO history (id, repo, branch, template, inputs, message, status, created) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                (
+                    &history.id,
+                    &history.repo,
+                    &history.branch,
+                    &history.template,
+                    inputs,
+                    &history.message,
+                    String::from(history.status.clone()),
+                    &history.created.to_rfc3339(),
+                ),
+            )
+            .map_err(|e| PersistError::into_history_error(format!("Failed to update history '{}'", history.id), e))?;
+
+        Ok(())
+    }
+
+    fn get_history(
+        &self,
+        branch: &str,
+        repo: &str,
+        limit: usize,
+    ) -> Result<Vec<CommitHistory>, PersistError> {
+        log::info!(
+            "retrieve history for branch '{}' and repo '{}'",
+            branch,
+            repo
+        );
+
+        let mut statement = self
+            .connection
+            .prepare(
+                "SELECT id, repo, branch, template, inputs, message, status, created FROM history WHERE repo = ?1 AND branch = ?2 ORDER BY created DESC LIMIT ?3",
+            )
+            .map_err(|e| PersistError::into_history_error("Failed to retrieve history", e))?;
+
+        let history: Vec<_> = statement
+            .query_map((repo.trim(), branch.trim(), limit), |row| {
+                CommitHistory::try_from(row)
+            })
+            .map_err(|e| PersistError::into_history_error("Failed to retrieve history", e))?
+            .collect::<Result<_, _>>()
+            .map_err(|e| PersistError::into_history_error("Failed to retrieve history", e))?;
+
+        Ok(history)
+    }
+
     fn persist_config(&self, config: &Config) -> Result<(), PersistError> {
         let key: String = config.key.clone().into();
         let path: String = config.path.to_string();
@@ -195,10 +372,13 @@ impl domain::adapters::Store for Sqlite {
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, path::Path};
+    use std::{
+        collections::{BTreeMap, HashMap},
+        path::Path,
+    };
 
     use crate::adapters::git::{Git, GitCommand};
-    use crate::domain::models::path::AbsolutePath;
+    use crate::domain::models::{path::AbsolutePath, CommitInputs, CommitStatus, Ticket};
     use crate::entry::Interactive;
     use crate::{app_context::AppContext, domain::adapters::Store};
 
@@ -256,6 +436,190 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn persist_branch_stores_data_as_yaml() -> anyhow::Result<()> {
+        // Arrange
+        let branch = Branch {
+            data: BTreeMap::from([("reviewer".into(), "alice".into())]),
+            ..fake_branch(None, None)?
+        };
+        let store = Sqlite::new(setup_db()?);
+
+        // Act
+        store.persist_branch(&branch)?;
+
+        // Assert
+        let data: Vec<u8> = store
+            .connection
+            .query_row("SELECT data FROM branch", [], |row| row.get(0))?;
+        assert_eq!("reviewer: alice\n", String::from_utf8(data)?);
+        assert_eq!(branch, select_branch_row(&store.connection)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn persist_branch_with_additional_tickets_round_trips() -> anyhow::Result<()> {
+        // Arrange
+        let repo = Faker.fake::<String>();
+        let name = Faker.fake::<String>();
+        let branch = Branch {
+            tickets: vec![
+                Ticket {
+                    ticket: "ABC-2".into(),
+                    link: Some("https://issues/ABC-2".into()),
+                },
+                Ticket {
+                    ticket: "ABC-3".into(),
+                    link: None,
+                },
+            ],
+            ..fake_branch(Some(name.clone()), Some(repo.clone()))?
+        };
+        let connection = setup_db()?;
+        let store = Sqlite::new(connection);
+
+        // Act
+        store.persist_branch(&branch)?;
+        let actual = store.get_branch(&name, &repo)?;
+
+        // Assert
+        assert_eq!(branch, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn persist_branch_with_last_used_round_trips() -> anyhow::Result<()> {
+        // Arrange
+        let repo = Faker.fake::<String>();
+        let name = Faker.fake::<String>();
+        let branch = Branch {
+            last_used: Some(Utc::now()),
+            ..fake_branch(Some(name.clone()), Some(repo.clone()))?
+        };
+        let store = Sqlite::new(setup_db()?);
+
+        // Act
+        store.persist_branch(&branch)?;
+        let actual = store.get_branch(&name, &repo)?;
+
+        // Assert
+        assert_eq!(branch, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_branch_moves_context_and_history() -> anyhow::Result<()> {
+        // Arrange
+        let repo = Faker.fake::<String>();
+        let (from, to) = (Faker.fake::<String>(), Faker.fake::<String>());
+        let branch = Branch::new(&from, &repo, None, Faker.fake(), Faker.fake());
+        let history = fake_history(repo.clone(), from.clone());
+
+        let mut store = Sqlite::new(setup_db()?);
+        store.persist_branch(&branch)?;
+        store.persist_history(&history)?;
+
+        // Act
+        let actual = store.rename_branch(&from, &to, &repo)?;
+
+        // Assert
+        assert_eq!(actual.name, format!("{repo}-{to}"));
+        assert_eq!(actual.ticket, to);
+        assert_eq!(actual.link, branch.link);
+        assert_eq!(actual.scope, branch.scope);
+
+        let error = store.get_branch(&from, &repo).unwrap_err();
+        assert!(matches!(error, PersistError::NotFound { name } if name == "branch"));
+
+        assert!(store.get_history(&from, &repo, 10)?.is_empty());
+        assert_eq!(store.get_history(&to, &repo, 10)?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_branch_keeps_explicit_ticket() -> anyhow::Result<()> {
+        // Arrange
+        let repo = Faker.fake::<String>();
+        let (from, to) = (Faker.fake::<String>(), Faker.fake::<String>());
+        let branch = Branch::new(&from, &repo, Some("ABC-1".into()), None, None);
+
+        let mut store = Sqlite::new(setup_db()?);
+        store.persist_branch(&branch)?;
+
+        // Act
+        let actual = store.rename_branch(&from, &to, &repo)?;
+
+        // Assert
+        assert_eq!(actual.ticket, "ABC-1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_branch_leaves_context_when_target_exists() -> anyhow::Result<()> {
+        // Arrange
+        let repo = Faker.fake::<String>();
+        let (from, to) = (Faker.fake::<String>(), Faker.fake::<String>());
+        let original = fake_branch(Some(from.clone()), Some(repo.clone()))?;
+        let existing = fake_branch(Some(to.clone()), Some(repo.clone()))?;
+
+        let mut store = Sqlite::new(setup_db()?);
+        store.persist_branch(&original)?;
+        store.persist_branch(&existing)?;
+
+        // Act
+        store.rename_branch(&from, &to, &repo).unwrap_err();
+
+        // Assert
+        assert_eq!(original, store.get_branch(&from, &repo)?);
+        assert_eq!(existing, store.get_branch(&to, &repo)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn archive_branch_keeps_context_marked_archived() -> anyhow::Result<()> {
+        // Arrange
+        let repo = Faker.fake::<String>();
+        let name = Faker.fake::<String>();
+        let branch = fake_branch(Some(name.clone()), Some(repo.clone()))?;
+        let store = Sqlite::new(setup_db()?);
+        store.persist_branch(&branch)?;
+
+        // Act
+        let archived = store.archive_branch(&name, &repo)?;
+
+        // Assert
+        assert!(archived.archived.is_some());
+        assert_eq!(branch.ticket, store.get_branch(&name, &repo)?.ticket);
+
+        Ok(())
+    }
+
+    #[test]
+    fn archive_branch_errors_when_not_found() -> anyhow::Result<()> {
+        let store = Sqlite::new(setup_db()?);
+
+        let error = store.archive_branch("name", "repo").unwrap_err();
+        assert!(matches!(error, PersistError::NotFound { name } if name == "branch"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_branch_errors_when_not_found() -> anyhow::Result<()> {
+        let mut store = Sqlite::new(setup_db()?);
+
+        let error = store.rename_branch("from", "to", "repo").unwrap_err();
+        assert!(matches!(error, PersistError::NotFound { name } if name == "branch"));
+
+        Ok(())
+    }
+
     #[test]
     fn get_branch_retrieves_correct_branch() -> anyhow::Result<()> {
         // Arrange
@@ -292,7 +656,7 @@ mod tests {
             .with_context(|| "Expected to find a matching branch")?;
 
         // Act
-        let branch = context.store.get_branch(&random_key, &repo)?;
+        let branch = context.store.get_branch(random_key, &repo)?;
 
         context.close()?;
 
@@ -388,6 +752,151 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn get_branches_retrieves_only_repo_branches_most_recent_first() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let repo = Faker.fake::<String>();
+
+        let older = Branch {
+            created: Utc::now() - chrono::Duration::days(1),
+            ..fake_branch(None, Some(repo.clone()))?
+        };
+        let newer = fake_branch(None, Some(repo.clone()))?;
+        let other = fake_branch(None, Some(format!("other-{repo}")))?;
+
+        for branch in [&older, &newer, &other] {
+            insert_branch(&store.connection, branch);
+        }
+
+        // Act
+        let branches = store.get_branches(&repo)?;
+
+        // Assert
+        assert_eq!(vec![newer, older], branches);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_branches_excludes_sibling_repo_sharing_name_prefix() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let repo = Faker.fake::<String>();
+
+        let branch = fake_branch(Some("main".into()), Some(repo.clone()))?;
+        let sibling = fake_branch(Some("feat".into()), Some(format!("{repo}-gateway")))?;
+
+        for branch in [&branch, &sibling] {
+            insert_branch(&store.connection, branch);
+        }
+
+        // Act
+        let branches = store.get_branches(&repo)?;
+        let siblings = store.get_branches(&format!("{repo}-gateway"))?;
+
+        // Assert
+        assert_eq!(vec![branch], branches);
+        assert_eq!(vec![sibling], siblings);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_branches_matches_branches_stored_without_repo_by_name() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let repo = Faker.fake::<String>();
+
+        let legacy = Branch {
+            repo: None,
+            ..fake_branch(None, Some(repo.clone()))?
+        };
+        insert_branch(&store.connection, &legacy);
+
+        // Act
+        let branches = store.get_branches(&repo)?;
+
+        // Assert
+        assert_eq!(vec![legacy], branches);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_branch_fills_in_repo_for_branches_stored_without_repo() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let (name, repo) = (Faker.fake::<String>(), Faker.fake::<String>());
+
+        let legacy = Branch {
+            repo: None,
+            ..fake_branch(Some(name.clone()), Some(repo.clone()))?
+        };
+        insert_branch(&store.connection, &legacy);
+
+        // Act
+        let branch = store.get_branch(&name, &repo)?;
+
+        // Assert
+        assert_eq!(Some(repo), branch.repo);
+
+        Ok(())
+    }
+
+    #[test]
+    fn persist_history_creates_and_updates_item() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let history = fake_history(Faker.fake(), Faker.fake());
+
+        // Act
+        store.persist_history(&history)?;
+
+        let updated = CommitHistory {
+            status: CommitStatus::Committed,
+            ..history.clone()
+        };
+        store.persist_history(&updated)?;
+
+        // Assert
+        let actual = store.get_history(&history.branch, &history.repo, 10)?;
+
+        assert_eq!(vec![updated], actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_history_retrieves_branch_history_most_recent_first_with_limit() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let (repo, branch) = (Faker.fake::<String>(), Faker.fake::<String>());
+
+        let oldest = CommitHistory {
+            created: Utc::now() - chrono::Duration::days(2),
+            ..fake_history(repo.clone(), branch.clone())
+        };
+        let older = CommitHistory {
+            created: Utc::now() - chrono::Duration::days(1),
+            ..fake_history(repo.clone(), branch.clone())
+        };
+        let newest = fake_history(repo.clone(), branch.clone());
+        let other = fake_history(repo.clone(), format!("other-{branch}"));
+
+        for history in [&oldest, &older, &newest, &other] {
+            store.persist_history(history)?;
+        }
+
+        // Act
+        let actual = store.get_history(&branch, &repo, 2)?;
+
+        // Assert
+        assert_eq!(vec![newest, older], actual);
+
+        Ok(())
+    }
+
     #[test]
     fn persist_config_creates_a_new_item_if_not_exists() -> anyhow::Result<()> {
         // Arrange
@@ -687,14 +1196,15 @@ mod tests {
 
     fn insert_branch(connection: &Connection, branch: &Branch) {
         connection.execute(
-            "INSERT INTO branch (name, ticket, data, created, link, scope) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            "INSERT INTO branch (name, ticket, data, created, link, scope, repo) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             (
                 &branch.name,
                 &branch.ticket,
-                &branch.data,
+                branch.data_bytes().unwrap(),
                 &branch.created.to_rfc3339(),
                 &branch.link,
-                &branch.scope
+                &branch.scope,
+                &branch.repo
             ),
         ).unwrap();
     }
@@ -733,27 +1243,56 @@ mod tests {
         ))
     }
 
+    fn fake_history(repo: String, branch: String) -> CommitHistory {
+        CommitHistory::new(
+            &repo,
+            &branch,
+            &Faker.fake::<String>(),
+            CommitInputs {
+                ticket: Faker.fake(),
+                scope: Faker.fake(),
+                message: Faker.fake(),
+                body: Faker.fake(),
+                footers: vec![(Faker.fake(), Faker.fake())],
+            },
+            &Faker.fake::<String>(),
+        )
+    }
+
     fn select_branch_row(conn: &Connection) -> anyhow::Result<Branch> {
-        let (name, ticket, data, created, link, scope) =
-            conn.query_row("SELECT * FROM branch", [], |row| {
+        let (name, repo, ticket, data, created, link, scope) = conn.query_row(
+            "SELECT name, repo, ticket, data, created, link, scope FROM branch",
+            [],
+            |row| {
                 Ok((
                     row.get::<_, String>(0)?,
-                    row.get::<_, String>(1)?,
-                    row.get::<_, Option<Vec<u8>>>(2)?,
-                    row.get::<_, String>(3)?,
-                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<Vec<u8>>>(3)?,
+                    row.get::<_, String>(4)?,
                     row.get::<_, Option<String>>(5)?,
+                    row.get::<_, Option<String>>(6)?,
                 ))
-            })?;
+            },
+        )?;
         let created = DateTime::parse_from_rfc3339(&created)?.with_timezone(&Utc);
 
+        let data = data
+            .map(|data| serde_yaml::from_slice(&data))
+            .transpose()?
+            .unwrap_or_default();
+
         Ok(Branch {
             name,
+            repo,
             ticket,
             data,
             created,
             link,
             scope,
+            tickets: vec![],
+            last_used: None,
+            archived: None,
         })
     }
 
@@ -792,7 +1331,7 @@ mod tests {
             &mut conn,
             MigrationContext {
                 default_configs: None,
-                version: 4,
+                version: 9,
             },
         )?;
         Ok(conn)
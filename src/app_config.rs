@@ -5,9 +5,9 @@ use directories::ProjectDirs;
 use rusqlite::Connection;
 
 use crate::{
-    adapters::{sqlite::Sqlite, Git},
+    adapters::sqlite::Sqlite,
     domain::{
-        adapters::{Git as _, GitSystem, Store},
+        adapters::{Git, Store},
         errors::{Errors, UserInputError},
         models::{
             path::{AbsolutePath, PathType},
@@ -21,9 +21,9 @@ pub struct AppConfig {
 }
 
 impl AppConfig {
-    pub fn new<S: GitSystem>(
+    pub fn new<G: Git>(
         once_off_config_path: Option<String>,
-        git: &Git<S>,
+        git: &G,
         store: &Sqlite,
     ) -> Result<AppConfig, Errors> {
         let config = match once_off_config_path {
@@ -116,7 +116,7 @@ mod tests {
     use fake::{Fake, Faker};
 
     use crate::{
-        adapters::GitCommand,
+        adapters::{self as git_adapters, GitCommand},
         domain::{adapters, models::ConfigStatus},
     };
 
@@ -124,7 +124,7 @@ mod tests {
 
     #[test]
     fn once_off_config_has_priority_1() {
-        let git: &dyn adapters::Git = &Git { git: GitCommand };
+        let git: &dyn adapters::Git = &git_adapters::Git { git: GitCommand };
 
         let once_path = abs_repo_directory();
         let valid_repo_dir = git.root_directory().unwrap();
@@ -150,7 +150,7 @@ mod tests {
         // Mock repo 'local' level configuration
         let repo_root_with_config = std::env::temp_dir();
         let config_repo = repo_root_with_config.join(".git-kit.yml");
-        let path_buf: PathBuf = config_repo.clone().into();
+        let path_buf: PathBuf = config_repo.clone();
         std::fs::File::create(&path_buf).unwrap();
 
         for key in [ConfigKey::Default, ConfigKey::User(Faker.fake())] {
@@ -0,0 +1,12 @@
+use clap::Args;
+
+#[derive(Debug, Clone, Args)]
+pub struct Arguments {
+    /// Branch the current branch was created from, defaults to the remote's default branch.
+    #[clap(long, value_parser)]
+    pub base: Option<String>,
+
+    /// Display the rewritten messages without changing any commits.
+    #[clap(long, value_parser)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,57 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::apply_ticket,
+        errors::Errors,
+    },
+    template_config::TemplateConfig,
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let base = match args.base {
+        Some(base) => base,
+        None => context.git.default_branch().map_err(Errors::Git)?,
+    };
+
+    let changes = apply_ticket::handler(
+        &context.git,
+        &context.store,
+        &templates.commit,
+        &base,
+        args.dry_run,
+    )?;
+
+    if changes.is_empty() {
+        println!("All commits since '{base}' already reference a ticket.");
+        return Ok(());
+    }
+
+    for change in &changes {
+        let short_hash = &change.hash[..change.hash.len().min(7)];
+
+        println!("{}", short_hash.yellow());
+        for line in change.before.trim().lines() {
+            println!("  {} {}", "-".red(), line.red());
+        }
+        for line in change.after.trim().lines() {
+            println!("  {} {}", "+".green(), line.green());
+        }
+    }
+
+    match args.dry_run {
+        true => println!("\n{} commits would be rewritten.", changes.len()),
+        false => println!("\nRewrote {} commits.", changes.len()),
+    }
+
+    Ok(())
+}
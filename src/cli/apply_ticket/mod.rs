@@ -0,0 +1,5 @@
+mod args;
+mod handler;
+
+pub use args::Arguments;
+pub use handler::handler;
@@ -0,0 +1,29 @@
+use clap::{Args, Subcommand};
+
+#[allow(clippy::almost_swapped)]
+#[allow(clippy::correctness)]
+#[derive(Debug, Clone, Subcommand)]
+pub enum Arguments {
+    /// Rename a branch moving any stored context along with it.
+    Rename(BranchRename),
+    /// List stored contexts for branches which no longer exist & offer to re-attach renamed branches.
+    Orphans(BranchOrphans),
+}
+
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct BranchRename {
+    /// Current name of the branch.
+    #[clap(value_parser)]
+    pub old: String,
+
+    /// New name for the branch.
+    #[clap(value_parser)]
+    pub new: String,
+}
+
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct BranchOrphans {
+    /// Re-attach every orphaned context to its renamed branch without prompting.
+    #[clap(long)]
+    pub attach: bool,
+}
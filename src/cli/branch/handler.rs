@@ -0,0 +1,92 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{prompt::Prompter, Git, Store},
+        commands::branch,
+        errors::Errors,
+    },
+    entry::Interactive,
+};
+
+use super::{
+    args::{BranchOrphans, BranchRename},
+    Arguments,
+};
+
+pub fn handler<G: Git, S: Store, P: Prompter>(
+    context: &mut AppContext<G, S>,
+    args: Arguments,
+    prompt: P,
+) -> Result<(), Errors> {
+    match args {
+        Arguments::Rename(args) => rename(context, args),
+        Arguments::Orphans(args) => orphans(context, args, prompt),
+    }
+}
+
+fn rename<G: Git, S: Store>(
+    context: &mut AppContext<G, S>,
+    args: BranchRename,
+) -> Result<(), Errors> {
+    let moved = branch::rename(&context.git, &mut context.store, &args.old, &args.new)?;
+
+    println!(
+        "Renamed branch {} to {}.",
+        args.old.bold(),
+        args.new.bold().green()
+    );
+
+    if moved.is_some() {
+        println!("Moved stored context to {}.", args.new.bold());
+    }
+
+    Ok(())
+}
+
+fn orphans<G: Git, S: Store, P: Prompter>(
+    context: &mut AppContext<G, S>,
+    args: BranchOrphans,
+    prompt: P,
+) -> Result<(), Errors> {
+    let orphans = branch::orphans(&context.git, &context.store)?;
+
+    if orphans.is_empty() {
+        println!("No orphaned branch contexts found.");
+        return Ok(());
+    }
+
+    for orphan in orphans {
+        let Some(renamed) = orphan.renamed else {
+            println!(
+                "{} ({}) no longer exists.",
+                orphan.name.bold(),
+                orphan.branch.ticket
+            );
+            continue;
+        };
+
+        println!(
+            "{} ({}) was renamed to {}.",
+            orphan.name.bold(),
+            orphan.branch.ticket,
+            renamed.bold().green()
+        );
+
+        let attach = match (args.attach, &context.interactive) {
+            (true, _) => true,
+            (false, Interactive::Enable) => prompt
+                .confirm(&format!("Re-attach context to '{renamed}'?"), true)
+                .map_err(Errors::UserInput)?,
+            (false, Interactive::Disable) => false,
+        };
+
+        if attach {
+            branch::attach(&context.git, &mut context.store, &orphan.name, &renamed)?;
+            println!("Re-attached context to {}.", renamed.bold());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use clap::Args;
+
+use crate::cli::format::Format;
+
+#[derive(Debug, Clone, Args)]
+pub struct Arguments {
+    /// Revision range of commits to include i.e. `v1.0.0..HEAD`.
+    #[clap(value_parser, default_value = "HEAD")]
+    pub range: String,
+
+    /// Output format of the changelog.
+    #[clap(short, long, arg_enum, value_parser, default_value = "markdown")]
+    pub format: Format,
+}
@@ -0,0 +1,27 @@
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::changelog,
+        errors::Errors,
+    },
+    template_config::TemplateConfig,
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let changelog =
+        changelog::handler(&context.git, &context.store, &templates.commit, &args.range)?;
+
+    let output = args.format.render(&changelog, |c| c.to_markdown())?;
+
+    println!("{output}");
+
+    Ok(())
+}
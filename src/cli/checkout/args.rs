@@ -4,7 +4,10 @@ use std::result::Result::Ok;
 use crate::{
     domain::{adapters::prompt::Prompter, commands::checkout::Checkout, errors::UserInputError},
     entry::Interactive,
-    utils::or_else_try::OrElseTry,
+    utils::{
+        or_else_try::OrElseTry,
+        string::{split_links, split_list},
+    },
 };
 
 #[derive(Debug, Args, Clone)]
@@ -13,46 +16,79 @@ pub struct Arguments {
     #[clap(value_parser)]
     pub name: String,
 
-    /// Issue ticket number related to the branch.
+    /// Issue ticket number related to the branch, may be repeated with the first being the primary ticket.
     #[clap(short, long, value_parser)]
-    pub ticket: Option<String>,
+    pub ticket: Vec<String>,
 
     /// Short describing a section of the codebase the changes relate to.
     #[clap(short, long, value_parser)]
     pub scope: Option<String>,
 
-    /// Issue ticket number link.
+    /// Issue ticket number link, may be repeated matching each ticket by position.
     #[clap(short, long, value_parser)]
-    pub link: Option<String>,
+    pub link: Vec<String>,
+
+    /// Inherit ticket, scope & link from the current branch when creating a new branch.
+    #[clap(long, overrides_with = "no_inherit")]
+    pub inherit: bool,
+
+    /// Don't inherit context from the current branch.
+    #[clap(long, overrides_with = "inherit")]
+    pub no_inherit: bool,
+
+    /// Branch, tag or commit to start a new branch from, defaults to the current HEAD.
+    #[clap(long, value_parser)]
+    pub from: Option<String>,
+
+    /// Create a branch tracking the remote branch of the same name i.e. 'origin/<name>'.
+    #[clap(long, conflicts_with = "from")]
+    pub track: bool,
 }
 
 impl Arguments {
+    /// Explicit choice of whether to inherit context, otherwise the configured default is used.
+    pub fn inherit(&self) -> Option<bool> {
+        match (self.inherit, self.no_inherit) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        }
+    }
+
     pub fn try_into_domain<P: Prompter>(
         &self,
         prompt: P,
         interactive: &Interactive,
+        ticket_link: Option<&str>,
     ) -> Result<Checkout, UserInputError> {
         let domain = match interactive {
             Interactive::Enable => Checkout {
                 name: self.name.clone(),
-                ticket: self
-                    .ticket
-                    .clone()
-                    .or_else_try(|| prompt.text("Ticket", None))?,
+                tickets: match self.ticket.is_empty() {
+                    true => prompt_list(&prompt, "Ticket", split_list)?,
+                    false => self.ticket.clone(),
+                },
                 scope: self
                     .scope
                     .clone()
                     .or_else_try(|| prompt.text("Scope", None))?,
-                link: self
-                    .link
-                    .clone()
-                    .or_else_try(|| prompt.text("Link", None))?,
+                // Links are rendered from the ticket when an issue tracker url pattern is configured.
+                links: match self.link.is_empty() && ticket_link.is_none() {
+                    true => prompt_list(&prompt, "Link", split_links)?,
+                    false => self.link.clone(),
+                },
+                parent: None,
+                from: self.from.clone(),
+                track: self.track,
             },
             Interactive::Disable => Checkout {
                 name: self.name.clone(),
-                ticket: self.ticket.clone(),
+                tickets: self.ticket.clone(),
                 scope: self.scope.clone(),
-                link: self.link.clone(),
+                links: self.link.clone(),
+                parent: None,
+                from: self.from.clone(),
+                track: self.track,
             },
         };
 
@@ -60,31 +96,46 @@ impl Arguments {
     }
 }
 
+/// Several tickets or links entered within a single prompt.
+fn prompt_list<P: Prompter>(
+    prompt: &P,
+    question: &str,
+    split: fn(&str) -> Vec<String>,
+) -> Result<Vec<String>, UserInputError> {
+    let value = prompt.text(question, None)?;
+
+    Ok(value.map(|v| split(&v)).unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use fake::{Fake, Faker};
 
-    use crate::domain::adapters::prompt::SelectItem;
+    use crate::cli::prompt_test::PromptTest;
 
     #[test]
     fn try_into_domain_with_no_interactive_prompts() -> anyhow::Result<()> {
         let args = fake_args();
 
         let prompt = PromptTest {
-            select_index: Err(anyhow::anyhow!("select should not be called")),
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
         };
 
         let actual = args
             .clone()
-            .try_into_domain(prompt, &Interactive::Disable)?;
+            .try_into_domain(prompt, &Interactive::Disable, None)?;
 
         let expected = Checkout {
             name: args.name.clone(),
-            ticket: args.ticket.clone(),
+            tickets: args.ticket.clone(),
             scope: args.scope.clone(),
-            link: args.link.clone(),
+            links: args.link.clone(),
+            parent: None,
+            from: args.from.clone(),
+            track: args.track,
         };
 
         assert_eq!(expected, actual);
@@ -95,26 +146,32 @@ mod tests {
     #[test]
     fn try_into_domain_with_interactive_prompt_is_used_if_none() -> anyhow::Result<()> {
         let args = Arguments {
-            ticket: None,
+            ticket: vec![],
             scope: None,
-            link: None,
+            link: vec![],
             ..fake_args()
         };
 
-        let text_prompt = Faker.fake::<Option<String>>();
+        let text_prompt = Some("ABC-1, ABC-2".to_string());
 
         let prompt = PromptTest {
-            select_index: Err(anyhow::anyhow!("select should not be called")),
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Ok(text_prompt.clone()),
+            ..PromptTest::default()
         };
 
-        let actual = args.clone().try_into_domain(prompt, &Interactive::Enable)?;
+        let actual = args
+            .clone()
+            .try_into_domain(prompt, &Interactive::Enable, None)?;
 
         let expected = Checkout {
             name: args.name.clone(),
-            ticket: text_prompt.clone(),
+            tickets: vec!["ABC-1".into(), "ABC-2".into()],
             scope: text_prompt.clone(),
-            link: text_prompt.clone(),
+            links: vec!["ABC-1,".into(), "ABC-2".into()],
+            parent: None,
+            from: args.from.clone(),
+            track: args.track,
         };
 
         assert_eq!(expected, actual);
@@ -126,24 +183,30 @@ mod tests {
     fn try_into_domain_with_interactive_prompt_is_not_used_if_value_is_already_provided(
     ) -> anyhow::Result<()> {
         let args = Arguments {
-            ticket: Some(Faker.fake()),
+            ticket: vec![Faker.fake(), Faker.fake()],
             scope: Some(Faker.fake()),
-            link: Some(Faker.fake()),
+            link: vec![Faker.fake()],
             ..fake_args()
         };
 
         let prompt = PromptTest {
-            select_index: Err(anyhow::anyhow!("select should not be called")),
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
         };
 
-        let actual = args.clone().try_into_domain(prompt, &Interactive::Enable)?;
+        let actual = args
+            .clone()
+            .try_into_domain(prompt, &Interactive::Enable, None)?;
 
         let expected = Checkout {
             name: args.name.clone(),
-            ticket: args.ticket.clone(),
+            tickets: args.ticket.clone(),
             scope: args.scope.clone(),
-            link: args.link.clone(),
+            links: args.link.clone(),
+            parent: None,
+            from: args.from.clone(),
+            track: args.track,
         };
 
         assert_eq!(expected, actual);
@@ -151,41 +214,30 @@ mod tests {
         Ok(())
     }
 
-    pub struct PromptTest {
-        select_index: anyhow::Result<usize>,
-        text_result: anyhow::Result<Option<String>>,
-    }
+    #[test]
+    fn try_into_domain_with_ticket_link_pattern_does_not_prompt_for_link() -> anyhow::Result<()> {
+        let args = Arguments {
+            ticket: vec![Faker.fake()],
+            scope: Some(Faker.fake()),
+            link: vec![],
+            ..fake_args()
+        };
 
-    impl Prompter for PromptTest {
-        fn text(&self, name: &str, _: Option<String>) -> Result<Option<String>, UserInputError> {
-            match &self.text_result {
-                Ok(option) => Ok(option.clone()),
-                Err(_) => Err(UserInputError::Validation {
-                    name: name.into(),
-                    message: "An error occurred in the mock prompter".into(),
-                }),
-            }
-        }
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
+        };
 
-        fn select<T>(
-            &self,
-            name: &str,
-            options: Vec<SelectItem<T>>,
-        ) -> Result<SelectItem<T>, UserInputError> {
-            let name: String = name.into();
-            match &self.select_index {
-                Ok(index) => options.into_iter().nth(index.clone()).ok_or_else(|| {
-                    UserInputError::Validation {
-                        name: name.clone(),
-                        message: "An error occurred in the mock prompter".into(),
-                    }
-                }),
-                Err(_) => Err(UserInputError::Validation {
-                    name: name.clone(),
-                    message: "An error occurred in the mock prompter".into(),
-                }),
-            }
-        }
+        let actual = args.clone().try_into_domain(
+            prompt,
+            &Interactive::Enable,
+            Some("https://tracker/browse/{ticket_num}"),
+        )?;
+
+        assert!(actual.links.is_empty());
+
+        Ok(())
     }
 
     fn fake_args() -> Arguments {
@@ -194,6 +246,10 @@ mod tests {
             ticket: Faker.fake(),
             scope: Faker.fake(),
             link: Faker.fake(),
+            inherit: false,
+            no_inherit: false,
+            from: None,
+            track: false,
         }
     }
 }
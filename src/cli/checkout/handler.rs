@@ -1,10 +1,12 @@
 use crate::{
     app_context::AppContext,
     domain::{
-        adapters::{prompt::Prompter, Git, Store},
+        adapters::{prompt::Prompter, CheckoutStatus, Git, Store},
         commands::checkout,
         errors::Errors,
     },
+    entry::Interactive,
+    template_config::TemplateConfig,
 };
 
 use super::Arguments;
@@ -14,11 +16,46 @@ pub fn handler<G: Git, S: Store, P: Prompter>(
     args: Arguments,
     prompt: P,
 ) -> Result<(), Errors> {
-    let checkout = args
-        .try_into_domain(prompt, &context.interactive)
+    let templates = TemplateConfig::new(&context.config.path)?;
+    let ticket_link = templates.commit.ticket_link.as_deref();
+
+    let status = checkout::status(&context.git, &args.name, args.from.as_deref(), args.track)?;
+
+    // Only newly created branches inherit, existing & tracked branches keep their own context.
+    let is_new = matches!(status, CheckoutStatus::New { .. });
+
+    // Read before checking out as the new branch becomes the current branch.
+    let parent = checkout::parent_context(&context.git, &context.store).filter(|_| is_new);
+
+    let inherit = match (&parent, args.inherit(), &context.interactive) {
+        (None, ..) => false,
+        (Some(_), Some(inherit), _) => inherit,
+        (Some(_), None, Interactive::Enable) => prompt
+            .confirm(
+                "Inherit ticket, scope & link from the current branch",
+                templates.checkout.inherit,
+            )
+            .map_err(Errors::UserInput)?,
+        (Some(_), None, Interactive::Disable) => templates.checkout.inherit,
+    };
+
+    // Inherited values aren't prompted for, any provided values still take precedence.
+    let interactive = match inherit {
+        true => Interactive::Disable,
+        false => context.interactive.clone(),
+    };
+
+    let mut checkout = args
+        .try_into_domain(prompt, &interactive, ticket_link)
         .map_err(Errors::UserInput)?;
 
-    checkout::handler(&context.git, &context.store, checkout)?;
+    checkout.parent = parent.filter(|_| inherit);
+
+    let branch = checkout::handler(&context.git, &context.store, checkout, status)?;
+
+    if let Some(link) = branch.ticket_link(ticket_link) {
+        println!("Ticket link: {link}");
+    }
 
     Ok(())
 }
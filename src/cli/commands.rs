@@ -8,7 +8,10 @@ use crate::{
     },
 };
 
-use super::{checkout, commit, config, context, templates};
+use super::{
+    apply_ticket, branch, changelog, checkout, commit, commit_log, config, context, finish,
+    history, hook, open, release_notes, reword, switch, tag, templates, version,
+};
 
 #[allow(clippy::almost_swapped)]
 #[allow(clippy::correctness)]
@@ -25,6 +28,35 @@ pub enum Commands {
     Config(config::Arguments),
     /// Display a list of configured templates.
     Templates,
+    /// Display rendered commit messages for the current branch, most recent first.
+    History(history::Arguments),
+    /// Generate a changelog by parsing commits within a revision range using the configured templates.
+    Changelog(changelog::Arguments),
+    /// Suggest or tag the next semantic version from commits since the latest version tag.
+    #[clap(subcommand)]
+    Version(version::Arguments),
+    /// Create an annotated version tag with a templated message listing commits since the previous version.
+    Tag(tag::Arguments),
+    /// List tickets referenced by commits within a revision range along with their commits.
+    ReleaseNotes(release_notes::Arguments),
+    /// Display recent commits decorated with values parsed from the configured templates.
+    Log(commit_log::Arguments),
+    /// Re-render an existing commit message through a template & rewrite the commit.
+    Reword(reword::Arguments),
+    /// Add the current branch's ticket to commits since the base branch that are missing one.
+    ApplyTicket(apply_ticket::Arguments),
+    /// Open the current branch's ticket link in the browser.
+    Open,
+    /// Rename branches along with their stored context & re-attach contexts orphaned by a rename.
+    #[clap(subcommand)]
+    Branch(branch::Arguments),
+    /// Merge or squash the current branch into its base branch with a templated message & archive its context.
+    Finish(finish::Arguments),
+    /// Pick a local branch to switch to showing its stored ticket & scope, most recently used first.
+    Switch,
+    /// Git hooks that render messages through the configured templates.
+    #[clap(subcommand)]
+    Hook(hook::Arguments),
 }
 
 impl Commands {
@@ -45,6 +77,19 @@ impl Commands {
                 &context.interactive,
             ),
             Commands::Templates => templates::handler(&context.config),
+            Commands::History(args) => history::handler(context, args),
+            Commands::Changelog(args) => changelog::handler(context, args),
+            Commands::Version(args) => version::handler(context, args),
+            Commands::ReleaseNotes(args) => release_notes::handler(context, args),
+            Commands::Log(args) => commit_log::handler(context, args),
+            Commands::Reword(args) => reword::handler(context, args, prompt),
+            Commands::ApplyTicket(args) => apply_ticket::handler(context, args),
+            Commands::Open => open::handler(context),
+            Commands::Branch(args) => branch::handler(context, args, prompt),
+            Commands::Switch => switch::handler(context, prompt),
+            Commands::Finish(args) => finish::handler(context, args, prompt),
+            Commands::Tag(args) => tag::handler(context, args),
+            Commands::Hook(args) => hook::handler(context, args),
         }
     }
 }
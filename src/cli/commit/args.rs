@@ -7,9 +7,11 @@ use crate::{
         adapters::prompt::{Prompter, SelectItem},
         commands::commit::Commit,
         errors::UserInputError,
+        models::Branch,
     },
     entry::Interactive,
-    template_config::{Template, TemplateConfig},
+    template_config::{StagedScopes, Template, TemplateConfig},
+    utils::or_else_try::OrElseTry,
 };
 
 #[derive(Debug, Args, PartialEq, Eq, Clone)]
@@ -28,6 +30,27 @@ pub struct Arguments {
     /// Short describing a section of the codebase the changes relate to.
     #[clap(short, long, value_parser)]
     pub scope: Option<String>,
+
+    /// Stage all changes to tracked files before committing.
+    #[clap(short, long)]
+    pub all: bool,
+
+    /// Commit with a previously rendered message from this branch's history.
+    #[clap(long)]
+    pub retry: bool,
+}
+
+/// Context used to pre-fill or offer choices when prompting for commit inputs.
+#[derive(Debug, Clone, Default)]
+pub struct Suggestions {
+    /// Stored context for the current branch.
+    pub branch: Option<Branch>,
+    /// Scopes recently used within the repository.
+    pub recent_scopes: Vec<String>,
+    /// Scopes matched from staged file paths.
+    pub staged_scopes: StagedScopes,
+    /// Template matched from staged file paths.
+    pub template: Option<String>,
 }
 
 impl Arguments {
@@ -36,28 +59,183 @@ impl Arguments {
         config: &TemplateConfig,
         prompter: P,
         interactive: &Interactive,
+        suggestions: Suggestions,
     ) -> Result<Commit, UserInputError> {
         let template = match &self.template {
             Some(template) => template.into(),
             None => Self::prompt_template_select(
                 config.commit.templates.clone(),
-                prompter,
+                suggestions.template.clone(),
+                &prompter,
                 interactive.to_owned(),
             )?,
         };
 
-        // TODO: Could we do a prompt if no ticket / args found ?
+        let template_name = template;
+        let template = config.get_template_config(&template_name)?.clone();
+
+        // Auto-fill the scope when all staged changes map to a single scope.
+        let scope = match template.contains_placeholder("scope") {
+            true => self
+                .scope
+                .clone()
+                .or_else(|| suggestions.staged_scopes.single()),
+            false => self.scope.clone(),
+        };
+
+        if interactive == &Interactive::Disable {
+            return Ok(Commit {
+                template_name,
+                template,
+                ticket: self.ticket.clone(),
+                message: self.message.clone(),
+                scope,
+                body: None,
+                footers: vec![],
+                ticket_link: config.commit.ticket_link.clone(),
+                ticket_separator: config.commit.ticket_separator.clone(),
+            });
+        }
+
+        let Suggestions {
+            branch,
+            recent_scopes,
+            staged_scopes,
+            ..
+        } = suggestions;
+
+        // Only prompt for placeholders the chosen template actually uses.
+        let ticket = match template.contains_placeholder("ticket_num") {
+            true => self
+                .ticket
+                .clone()
+                .or_else_try(|| prompter.text("Ticket", branch.clone().map(|b| b.ticket)))?,
+            false => self.ticket.clone(),
+        };
+
+        let scope = match template.contains_placeholder("scope") {
+            true => scope.or_else_try(|| {
+                let scopes = staged_scopes
+                    .scopes
+                    .into_iter()
+                    .map(|scope| (scope, Some("staged changes".to_string())))
+                    .chain(config.commit.scopes.iter().map(|s| (s.clone(), None)))
+                    .chain(recent_scopes.into_iter().map(|s| (s, None)))
+                    .collect();
+
+                Self::prompt_scope(&prompter, branch.and_then(|b| b.scope), scopes)
+            })?,
+            false => scope,
+        };
+
+        let message = match template.contains_placeholder("message") {
+            true => self
+                .message
+                .clone()
+                .or_else_try(|| prompter.text("Message", None))?,
+            false => self.message.clone(),
+        };
+
+        let body = Self::prompt_body(&template, &prompter)?;
+        let footers = Self::prompt_footers(&template, &prompter)?;
+
         Ok(Commit {
-            template: config.get_template_config(&template)?.clone(),
-            ticket: self.ticket.clone(),
-            message: self.message.clone(),
-            scope: self.scope.clone(),
+            template_name,
+            template,
+            ticket,
+            message,
+            scope,
+            body,
+            footers,
+            ticket_link: config.commit.ticket_link.clone(),
+            ticket_separator: config.commit.ticket_separator.clone(),
         })
     }
 
+    fn prompt_scope<P: Prompter>(
+        prompter: &P,
+        default: Option<String>,
+        scopes: Vec<(String, Option<String>)>,
+    ) -> Result<Option<String>, UserInputError> {
+        let mut items: Vec<SelectItem<Option<String>>> = vec![];
+
+        let default_scope = default
+            .clone()
+            .map(|scope| (scope, Some("current branch".to_string())));
+
+        for (scope, description) in default_scope.into_iter().chain(scopes) {
+            if items.iter().any(|item| item.name == scope) {
+                continue;
+            }
+
+            items.push(SelectItem {
+                name: scope.clone(),
+                value: Some(scope),
+                description,
+            });
+        }
+
+        if items.is_empty() {
+            return prompter.text("Scope", default);
+        }
+
+        items.push(SelectItem {
+            name: "other".into(),
+            value: None,
+            description: Some("enter a custom scope".into()),
+        });
+
+        match prompter.select("Scope", items)?.value {
+            Some(scope) => Ok(Some(scope)),
+            None => prompter.text("Scope", None),
+        }
+    }
+
+    fn prompt_body<P: Prompter>(
+        template: &Template,
+        prompter: &P,
+    ) -> Result<Option<String>, UserInputError> {
+        if !template.contains_placeholder("body") {
+            return Ok(None);
+        }
+
+        prompter.editor("Body", None)
+    }
+
+    fn prompt_footers<P: Prompter>(
+        template: &Template,
+        prompter: &P,
+    ) -> Result<Vec<(String, String)>, UserInputError> {
+        if template.footers.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let items = template
+            .footers
+            .iter()
+            .map(|footer| SelectItem {
+                name: footer.token.clone(),
+                value: footer.token.clone(),
+                description: footer.description.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let selected = prompter.multi_select("Footers", items)?;
+
+        let mut footers = vec![];
+        for item in selected {
+            if let Some(value) = prompter.text(&item.name, None)? {
+                footers.push((item.value, value));
+            }
+        }
+
+        Ok(footers)
+    }
+
     fn prompt_template_select<P: Prompter>(
         templates: HashMap<String, Template>,
-        prompter: P,
+        suggested: Option<String>,
+        prompter: &P,
         interactive: Interactive,
     ) -> Result<String, UserInputError> {
         if interactive == Interactive::Disable {
@@ -66,7 +244,18 @@ impl Arguments {
             });
         }
 
-        let items = templates
+        let items = Self::template_select_items(templates, suggested);
+        let selected = prompter.select("Template", items)?;
+
+        Ok(selected.name)
+    }
+
+    /// Suggested template is placed first so it's pre-selected within the prompt.
+    pub(crate) fn template_select_items(
+        templates: HashMap<String, Template>,
+        suggested: Option<String>,
+    ) -> Vec<SelectItem<String>> {
+        let mut items = templates
             .into_iter()
             .map(|(name, template)| SelectItem {
                 name: name.clone(),
@@ -75,21 +264,27 @@ impl Arguments {
             })
             .collect::<Vec<_>>();
 
-        let selected = prompter.select("Template", items)?;
+        if let Some(index) = items
+            .iter()
+            .position(|i| Some(&i.name) == suggested.as_ref())
+        {
+            let mut item = items.remove(index);
+            item.description = item.description.map(|d| format!("{d} (suggested)"));
+            items.insert(0, item);
+        }
 
-        Ok(selected.name)
+        items
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anyhow::Context;
     use fake::{Fake, Faker};
 
     use crate::{
-        domain::{adapters::prompt::SelectItem, errors::UserInputError},
-        template_config::CommitConfig,
+        cli::prompt_test::PromptTest,
+        template_config::{CommitConfig, Footer},
     };
 
     #[test]
@@ -107,19 +302,29 @@ mod tests {
         let prompt = PromptTest {
             select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
         };
 
-        let actual = args
-            .clone()
-            .try_into_domain(&config, prompt, &Interactive::Disable)?;
+        let actual = args.clone().try_into_domain(
+            &config,
+            prompt,
+            &Interactive::Disable,
+            Suggestions::default(),
+        )?;
 
         let expected = Commit {
+            template_name: key.clone(),
             template: value,
             ticket: args.ticket.clone(),
             scope: args.scope.clone(),
             message: args.message.clone(),
+            body: None,
+            footers: vec![],
+            ticket_link: None,
+            ticket_separator: None,
         };
 
+        assert_eq!(expected.template_name, actual.template_name);
         assert_eq!(expected.template.content, actual.template.content);
         assert_eq!(expected.message, actual.message);
         assert_eq!(expected.scope, actual.scope);
@@ -138,6 +343,8 @@ mod tests {
             ticket: None,
             scope: None,
             message: None,
+            all: false,
+            retry: false,
         };
 
         let config = fake_template_config(Some((key.clone(), value.clone())));
@@ -147,17 +354,26 @@ mod tests {
         let prompt = PromptTest {
             select_item_name: Ok(key.clone()),
             text_result: Ok(text_prompt.clone()),
+            ..PromptTest::default()
         };
 
-        let actual = args
-            .clone()
-            .try_into_domain(&config, prompt, &Interactive::Enable)?;
+        let actual = args.clone().try_into_domain(
+            &config,
+            prompt,
+            &Interactive::Enable,
+            Suggestions::default(),
+        )?;
 
         let expected = Commit {
+            template_name: key.clone(),
             template: value,
             ticket: text_prompt.clone(),
             scope: text_prompt.clone(),
             message: text_prompt.clone(),
+            body: None,
+            footers: vec![],
+            ticket_link: None,
+            ticket_separator: None,
         };
 
         assert_eq!(expected.template.description, actual.template.description);
@@ -179,7 +395,8 @@ mod tests {
             ticket: Some(Faker.fake()),
             scope: Some(Faker.fake()),
             message: Some(Faker.fake()),
-            ..fake_args()
+            all: false,
+            retry: false,
         };
 
         let config = fake_template_config(Some((key.clone(), value.clone())));
@@ -187,17 +404,26 @@ mod tests {
         let prompt = PromptTest {
             select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
         };
 
-        let actual = args
-            .clone()
-            .try_into_domain(&config, prompt, &Interactive::Enable)?;
+        let actual = args.clone().try_into_domain(
+            &config,
+            prompt,
+            &Interactive::Enable,
+            Suggestions::default(),
+        )?;
 
         let expected = Commit {
+            template_name: key.clone(),
             template: value,
             ticket: args.ticket.clone(),
             scope: args.scope.clone(),
             message: args.message.clone(),
+            body: None,
+            footers: vec![],
+            ticket_link: None,
+            ticket_separator: None,
         };
 
         assert_eq!(expected.template.description, actual.template.description);
@@ -221,58 +447,338 @@ mod tests {
         let prompt = PromptTest {
             select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
         };
 
         let error = args
             .clone()
-            .try_into_domain(&config, prompt, &Interactive::Disable)
+            .try_into_domain(
+                &config,
+                prompt,
+                &Interactive::Disable,
+                Suggestions::default(),
+            )
             .unwrap_err();
 
         assert_eq!(error.to_string(), "Missing required \"template\" input");
     }
 
-    pub struct PromptTest {
-        select_item_name: anyhow::Result<String>,
-        text_result: anyhow::Result<Option<String>>,
+    #[test]
+    fn try_into_domain_with_interactive_prompts_for_body_and_selected_footers() -> anyhow::Result<()>
+    {
+        let key = Faker.fake::<String>();
+        let value = Template {
+            content: "{message}\n\n{body}\n\n{footers}".into(),
+            footers: vec![
+                Footer {
+                    token: "BREAKING CHANGE".into(),
+                    description: None,
+                },
+                Footer {
+                    token: "Co-authored-by".into(),
+                    description: Some(Faker.fake()),
+                },
+            ],
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key.clone(), value.clone())));
+
+        let body = Faker.fake::<String>();
+        let footer = Faker.fake::<String>();
+
+        let prompt = PromptTest {
+            text_result: Ok(Some(footer.clone())),
+            editor_result: Ok(Some(body.clone())),
+            multi_select_names: Ok(vec!["Co-authored-by".into()]),
+            ..PromptTest::default()
+        };
+
+        let actual = args.try_into_domain(
+            &config,
+            prompt,
+            &Interactive::Enable,
+            Suggestions::default(),
+        )?;
+
+        assert_eq!(Some(body), actual.body);
+        assert_eq!(vec![("Co-authored-by".to_string(), footer)], actual.footers);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_interactive_disabled_does_not_prompt_for_body_or_footers(
+    ) -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+        let value = Template {
+            content: "{message}\n\n{body}\n\n{footers}".into(),
+            footers: vec![Footer {
+                token: "BREAKING CHANGE".into(),
+                description: None,
+            }],
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key.clone(), value.clone())));
+
+        let actual = args.try_into_domain(
+            &config,
+            PromptTest::default(),
+            &Interactive::Disable,
+            Suggestions::default(),
+        )?;
+
+        assert_eq!(None, actual.body);
+        assert!(actual.footers.is_empty());
+
+        Ok(())
     }
 
-    impl Prompter for PromptTest {
-        fn text(&self, name: &str, _: Option<String>) -> Result<Option<String>, UserInputError> {
-            match &self.text_result {
-                Ok(option) => Ok(option.clone()),
-                Err(_) => Err(UserInputError::Validation {
-                    name: name.into(),
-                    message: "error".into(),
-                }),
-            }
+    #[test]
+    fn try_into_domain_with_interactive_prompts_for_missing_placeholders_used_by_template(
+    ) -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+        let value = Template {
+            content: "{scope}: [{ticket_num}] {message}".into(),
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            ticket: None,
+            scope: None,
+            message: None,
+            all: false,
+            retry: false,
+        };
+
+        let mut config = fake_template_config(Some((key.clone(), value.clone())));
+        config.commit.scopes = vec!["parser".into(), "cli".into()];
+
+        let text = Faker.fake::<String>();
+        let prompt = PromptTest {
+            select_item_name: Ok("cli".into()),
+            text_result: Ok(Some(text.clone())),
+            ..PromptTest::default()
+        };
+
+        let actual = args.try_into_domain(
+            &config,
+            prompt,
+            &Interactive::Enable,
+            Suggestions {
+                recent_scopes: vec!["recent".into()],
+                ..Suggestions::default()
+            },
+        )?;
+
+        assert_eq!(Some(text.clone()), actual.ticket);
+        assert_eq!(Some(text), actual.message);
+        assert_eq!(Some("cli".into()), actual.scope);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_interactive_scope_defaults_to_branch_scope_option() -> anyhow::Result<()>
+    {
+        let key = Faker.fake::<String>();
+        let value = Template {
+            content: "{scope}".into(),
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key.clone(), value.clone())));
+
+        let branch = Branch::new(
+            &Faker.fake::<String>(),
+            &Faker.fake::<String>(),
+            None,
+            None,
+            Some("branch-scope".into()),
+        );
+
+        let prompt = PromptTest {
+            select_item_name: Ok("branch-scope".into()),
+            ..PromptTest::default()
+        };
+
+        let actual = Arguments {
+            scope: None,
+            ..args
         }
+        .try_into_domain(
+            &config,
+            prompt,
+            &Interactive::Enable,
+            Suggestions {
+                branch: Some(branch),
+                ..Suggestions::default()
+            },
+        )?;
+
+        assert_eq!(Some("branch-scope".into()), actual.scope);
 
-        fn select<T>(
-            &self,
-            name: &str,
-            options: Vec<SelectItem<T>>,
-        ) -> Result<SelectItem<T>, UserInputError> {
-            match &self.select_item_name {
-                Ok(name) => options
-                    .into_iter()
-                    .find(|i| i.name == name.clone())
-                    .context("Failed to get item")
-                    .map_err(|_| UserInputError::Validation {
-                        name: name.into(),
-                        message: "error".into(),
-                    }),
-                Err(_) => Err(UserInputError::Validation {
-                    name: name.into(),
-                    message: "error".into(),
-                }),
-            }
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_interactive_skips_placeholders_not_used_by_template(
+    ) -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+        let value = Template {
+            content: "{message}".into(),
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            ticket: None,
+            scope: None,
+            message: Some(Faker.fake()),
+            all: false,
+            retry: false,
+        };
+
+        let config = fake_template_config(Some((key.clone(), value.clone())));
+
+        let actual = args.clone().try_into_domain(
+            &config,
+            PromptTest::default(),
+            &Interactive::Enable,
+            Suggestions::default(),
+        )?;
+
+        assert_eq!(None, actual.ticket);
+        assert_eq!(None, actual.scope);
+        assert_eq!(args.message, actual.message);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_single_staged_scope_auto_fills_scope() -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+        let value = Template {
+            content: "{scope}".into(),
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            scope: None,
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key.clone(), value.clone())));
+
+        let actual = args.try_into_domain(
+            &config,
+            PromptTest::default(),
+            &Interactive::Disable,
+            Suggestions {
+                staged_scopes: StagedScopes {
+                    scopes: vec!["parser".into()],
+                    all_matched: true,
+                },
+                ..Suggestions::default()
+            },
+        )?;
+
+        assert_eq!(Some("parser".into()), actual.scope);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_several_staged_scopes_offers_selection() -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+        let value = Template {
+            content: "{scope}".into(),
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            scope: None,
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key.clone(), value.clone())));
+
+        let prompt = PromptTest {
+            select_item_name: Ok("cli".into()),
+            ..PromptTest::default()
+        };
+
+        let actual = args.try_into_domain(
+            &config,
+            prompt,
+            &Interactive::Enable,
+            Suggestions {
+                staged_scopes: StagedScopes {
+                    scopes: vec!["parser".into(), "cli".into()],
+                    all_matched: true,
+                },
+                ..Suggestions::default()
+            },
+        )?;
+
+        assert_eq!(Some("cli".into()), actual.scope);
+
+        Ok(())
+    }
+
+    #[test]
+    fn template_select_items_places_suggested_template_first() {
+        let config = fake_template_config(None);
+
+        for _ in 0..5 {
+            let items = Arguments::template_select_items(
+                config.commit.templates.clone(),
+                Some("option-2".into()),
+            );
+
+            assert_eq!(3, items.len());
+            assert_eq!("option-2", items[0].name);
+            assert_eq!(Some("option-2 (suggested)".into()), items[0].description);
         }
     }
 
+    #[test]
+    fn template_select_items_with_unknown_suggestion_keeps_all_templates() {
+        let config = fake_template_config(None);
+
+        let items =
+            Arguments::template_select_items(config.commit.templates.clone(), Some("other".into()));
+
+        assert_eq!(3, items.len());
+        assert!(items
+            .iter()
+            .all(|i| !i.description.clone().unwrap().contains("suggested")));
+    }
+
     fn fake_template(description: &str) -> Template {
         Template {
             description: description.into(),
             content: Faker.fake(),
+            footers: vec![],
         }
     }
 
@@ -287,9 +793,15 @@ mod tests {
             map.insert(key, item);
         }
 
-        let config = CommitConfig { templates: map };
+        let config = CommitConfig {
+            templates: map,
+            ..CommitConfig::default()
+        };
 
-        TemplateConfig { commit: config }
+        TemplateConfig {
+            commit: config,
+            ..TemplateConfig::default()
+        }
     }
 
     fn fake_args() -> Arguments {
@@ -298,6 +810,8 @@ mod tests {
             ticket: Faker.fake(),
             scope: Faker.fake(),
             message: Faker.fake(),
+            all: Faker.fake(),
+            retry: Faker.fake(),
         }
     }
 }
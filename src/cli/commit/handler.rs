@@ -1,14 +1,19 @@
 use crate::{
     app_context::AppContext,
     domain::{
-        adapters::{prompt::Prompter, Git, Store},
+        adapters::{
+            prompt::{Prompter, SelectItem},
+            Git, Store,
+        },
         commands::commit,
-        errors::Errors,
+        errors::{Errors, GitError},
+        models::CommitHistory,
     },
+    entry::Interactive,
     template_config::TemplateConfig,
 };
 
-use super::Arguments;
+use super::{args::Suggestions, Arguments};
 
 pub fn handler<G: Git, S: Store, P: Prompter>(
     context: &AppContext<G, S>,
@@ -16,11 +21,138 @@ pub fn handler<G: Git, S: Store, P: Prompter>(
     prompter: P,
 ) -> Result<(), Errors> {
     let templates = TemplateConfig::new(&context.config.path)?;
+
+    // Check before prompting so nothing typed is lost to a failing commit.
+    preflight(context, args.all, &prompter)?;
+
+    let repo_name = context.git.repository_name().map_err(Errors::Git)?;
+    let branch_name = context.git.branch_name().map_err(Errors::Git)?;
+
+    if args.retry {
+        let history = select_history(context, &repo_name, &branch_name, &prompter)?;
+        commit::retry(&context.git, &context.store, history)?;
+
+        return Ok(());
+    }
+
+    let staged_files = staged_files(&context.git, &templates);
+
+    let suggestions = Suggestions {
+        branch: context.store.get_branch(&branch_name, &repo_name).ok(),
+        recent_scopes: recent_scopes(&context.store, &repo_name),
+        staged_scopes: templates.commit.staged_scopes(&staged_files).map_err(|e| {
+            Errors::Configuration {
+                message: "Failed to parse 'scope_paths' globs".into(),
+                source: e,
+            }
+        })?,
+        template: templates
+            .commit
+            .suggested_template(&staged_files)
+            .map_err(|e| Errors::Configuration {
+                message: "Failed to parse 'template_paths' globs".into(),
+                source: e,
+            })?,
+    };
+
     let commit = args
-        .try_into_domain(&templates, prompter, &context.interactive)
+        .try_into_domain(&templates, prompter, &context.interactive, suggestions)
         .map_err(Errors::UserInput)?;
 
     commit::handler(&context.git, &context.store, commit)?;
 
     Ok(())
 }
+
+fn preflight<G: Git, S: Store, P: Prompter>(
+    context: &AppContext<G, S>,
+    stage_all: bool,
+    prompter: &P,
+) -> Result<(), Errors> {
+    match commit::preflight(&context.git, stage_all) {
+        Err(Errors::Git(GitError::NothingStaged)) if context.interactive == Interactive::Enable => {
+            let stage = prompter
+                .confirm("Nothing staged, stage all tracked changes", false)
+                .map_err(Errors::UserInput)?;
+
+            if !stage {
+                return Err(Errors::Git(GitError::NothingStaged));
+            }
+
+            commit::preflight(&context.git, true)
+        }
+        result => result,
+    }
+}
+
+/// Most recent message is used unless interactive, in which case one can be chosen.
+fn select_history<G: Git, S: Store, P: Prompter>(
+    context: &AppContext<G, S>,
+    repo_name: &str,
+    branch_name: &str,
+    prompter: &P,
+) -> Result<CommitHistory, Errors> {
+    let mut history = context
+        .store
+        .get_history(branch_name, repo_name, 10)
+        .map_err(Errors::PersistError)?;
+
+    if history.is_empty() {
+        return Err(Errors::ValidationError {
+            message: format!("No commit history found for branch '{branch_name}'"),
+        });
+    }
+
+    if context.interactive == Interactive::Disable || history.len() == 1 {
+        return Ok(history.remove(0));
+    }
+
+    let items = history
+        .into_iter()
+        .map(|entry| SelectItem {
+            name: entry.subject().to_string(),
+            description: Some(format!(
+                "{} {:?} {}",
+                entry.template,
+                entry.status,
+                entry.created.format("%Y-%m-%d %H:%M")
+            )),
+            value: entry,
+        })
+        .collect();
+
+    let selected = prompter
+        .select("Commit message", items)
+        .map_err(Errors::UserInput)?;
+
+    Ok(selected.value)
+}
+
+fn staged_files<G: Git>(git: &G, templates: &TemplateConfig) -> Vec<String> {
+    let commit = &templates.commit;
+    if commit.scope_paths.is_empty() && commit.template_paths.is_empty() {
+        return vec![];
+    }
+
+    git.staged_files().unwrap_or_else(|e| {
+        log::error!("Failed to retrieve staged files: {}", e);
+        vec![]
+    })
+}
+
+fn recent_scopes<S: Store>(store: &S, repo_name: &str) -> Vec<String> {
+    let branches = store.get_branches(repo_name).unwrap_or_else(|e| {
+        log::error!("Failed to retrieve recent scopes: {}", e);
+        vec![]
+    });
+
+    let mut scopes: Vec<String> = vec![];
+    for scope in branches.into_iter().filter_map(|b| b.scope) {
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+
+    scopes.truncate(10);
+    scopes
+}
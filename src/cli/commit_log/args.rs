@@ -0,0 +1,36 @@
+use clap::Args;
+
+use crate::domain::commands::commit_log::LogFilter;
+
+#[derive(Debug, Clone, Args)]
+pub struct Arguments {
+    /// Revision range of commits to display i.e. `main..HEAD`.
+    #[clap(value_parser, default_value = "HEAD")]
+    pub range: String,
+
+    /// Only show commits matching a template name i.e. `feat`.
+    #[clap(long = "type", value_parser)]
+    pub template: Option<String>,
+
+    /// Only show commits with a scope.
+    #[clap(short, long, value_parser)]
+    pub scope: Option<String>,
+
+    /// Only show commits referencing a ticket.
+    #[clap(short, long, value_parser)]
+    pub ticket: Option<String>,
+
+    /// Maximum number of commits to display.
+    #[clap(short = 'n', long, value_parser, default_value_t = 20)]
+    pub limit: usize,
+}
+
+impl Arguments {
+    pub fn filter(&self) -> LogFilter {
+        LogFilter {
+            template: self.template.clone(),
+            scope: self.scope.clone(),
+            ticket: self.ticket.clone(),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::io::IsTerminal;
+
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::{changelog::Entry, commit_log},
+        errors::Errors,
+    },
+    template_config::{Template, TemplateConfig},
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let entries = commit_log::handler(
+        &context.git,
+        &context.store,
+        &templates.commit,
+        &args.range,
+        &args.filter(),
+        args.limit,
+    )?;
+
+    let hyperlinks = std::io::stdout().is_terminal();
+
+    for entry in entries {
+        let icon = entry
+            .template
+            .as_ref()
+            .and_then(|name| templates.commit.templates.get(name))
+            .and_then(Template::icon);
+
+        println!("{}", format_entry(&entry, icon, hyperlinks));
+    }
+
+    Ok(())
+}
+
+fn format_entry(entry: &Entry, icon: Option<&str>, hyperlinks: bool) -> String {
+    let mut parts = vec![entry.hash[..entry.hash.len().min(7)].yellow().to_string()];
+
+    if let Some(icon) = icon {
+        parts.push(icon.into());
+    }
+
+    if let Some(template) = &entry.template {
+        parts.push(template.bold().green().to_string());
+    }
+
+    if let Some(scope) = &entry.scope {
+        parts.push(format!("({scope})").cyan().to_string());
+    }
+
+    if let Some(ticket) = &entry.ticket {
+        let ticket = match (&entry.link, hyperlinks) {
+            // OSC 8 escape sequence renders a clickable link within supported terminals.
+            (Some(link), true) => format!("\x1b]8;;{link}\x1b\\{ticket}\x1b]8;;\x1b\\"),
+            (Some(link), false) => format!("{ticket} <{link}>"),
+            (None, _) => ticket.to_string(),
+        };
+        parts.push(format!("[{ticket}]").magenta().to_string());
+    }
+
+    parts.push(entry.message.clone());
+    parts.push(
+        format!(
+            "— {}, {}",
+            entry.author,
+            entry.date.format("%Y-%m-%d %H:%M")
+        )
+        .dimmed()
+        .to_string(),
+    );
+
+    parts.join(" ")
+}
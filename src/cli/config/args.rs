@@ -86,15 +86,13 @@ fn prompt_configuration_select<P: Prompter>(
 
 #[cfg(test)]
 mod tests {
-    use anyhow::Context;
     use fake::{Fake, Faker};
     use std::path::PathBuf;
 
     use super::*;
-    use crate::domain::{
-        adapters::prompt::{Prompter, SelectItem},
-        errors::UserInputError,
-        models::{
+    use crate::{
+        cli::prompt_test::PromptTest,
+        domain::models::{
             path::{AbsolutePath, PathType},
             ConfigStatus,
         },
@@ -106,6 +104,7 @@ mod tests {
         let config = fake_config();
         let selector = PromptTest {
             select_item_name: Ok(config.key.clone().into()),
+            ..PromptTest::default()
         };
         let configurations = vec![fake_config(), config.clone(), fake_config()];
 
@@ -123,6 +122,7 @@ mod tests {
         let config = fake_config();
         let selector = PromptTest {
             select_item_name: Ok(config.key.clone().into()),
+            ..PromptTest::default()
         };
         let configurations = vec![fake_config(), config.clone(), fake_config()];
 
@@ -152,38 +152,4 @@ mod tests {
             status: ConfigStatus::Active,
         }
     }
-
-    struct PromptTest {
-        select_item_name: anyhow::Result<String>,
-    }
-
-    impl Prompter for PromptTest {
-        fn text(&self, name: &str, _: Option<String>) -> Result<Option<String>, UserInputError> {
-            Err(UserInputError::Validation {
-                name: name.into(),
-                message: "error occurred in mock".into(),
-            })
-        }
-
-        fn select<T>(
-            &self,
-            name: &str,
-            options: Vec<SelectItem<T>>,
-        ) -> Result<SelectItem<T>, UserInputError> {
-            match &self.select_item_name {
-                Ok(name) => Ok(options
-                    .into_iter()
-                    .find(|i| i.name == name.clone())
-                    .context("Failed to get item")
-                    .map_err(|_| UserInputError::Validation {
-                        name: name.into(),
-                        message: "Failed to get item".into(),
-                    })?),
-                Err(_) => Err(UserInputError::Validation {
-                    name: name.into(),
-                    message: "error occured in mock".into(),
-                }),
-            }
-        }
-    }
 }
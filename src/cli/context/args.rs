@@ -1,4 +1,4 @@
-use clap::Args;
+use clap::{Args, Subcommand};
 
 use crate::{
     domain::{
@@ -6,22 +6,54 @@ use crate::{
         models::Branch,
     },
     entry::Interactive,
-    utils::or_else_try::OrElseTry,
+    utils::{
+        or_else_try::OrElseTry,
+        string::{split_links, split_list},
+    },
 };
 
 #[derive(Debug, Clone, Args)]
+#[clap(args_conflicts_with_subcommands = true)]
 pub struct Arguments {
+    #[clap(subcommand)]
+    pub action: Option<Action>,
+
     /// Issue ticket number related to the current branch.
     #[clap(value_parser)]
     pub ticket: Option<String>,
 
+    /// Additional issue ticket number related to the current branch, may be repeated.
+    #[clap(short = 't', long = "ticket", value_parser)]
+    pub tickets: Vec<String>,
+
     /// Short describing a section of the codebase the changes relate to.
     #[clap(short, long, value_parser)]
     pub scope: Option<String>,
 
-    /// Issue ticket number link.
+    /// Issue ticket number link, may be repeated matching each ticket by position.
     #[clap(short, long, value_parser)]
-    pub link: Option<String>,
+    pub link: Vec<String>,
+}
+
+#[allow(clippy::almost_swapped)]
+#[allow(clippy::correctness)]
+#[derive(Debug, Clone, Subcommand)]
+pub enum Action {
+    /// Set a value for the current branch available as the `{key}` placeholder in commit templates.
+    Set {
+        /// Placeholder name i.e. `reviewer`.
+        #[clap(value_parser)]
+        key: String,
+        #[clap(value_parser)]
+        value: String,
+    },
+    /// Remove a value from the current branch.
+    Unset {
+        #[clap(value_parser)]
+        key: String,
+    },
+    /// Display the context stored for the current branch.
+    Show,
 }
 
 impl Arguments {
@@ -29,26 +61,44 @@ impl Arguments {
         &self,
         branch: Option<Branch>,
         prompt: P,
+        ticket_link: Option<&str>,
     ) -> Result<Context, UserInputError> {
-        let ticket = self
-            .ticket
-            .clone()
-            .or_else_try(|| prompt.text("Ticket:", branch.clone().map(|b| b.ticket)))?;
+        let tickets = match self.all_tickets() {
+            tickets if tickets.is_empty() => {
+                let default = branch.clone().map(|b| {
+                    b.all_tickets()
+                        .into_iter()
+                        .map(|t| t.ticket)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                });
+
+                prompt
+                    .text("Ticket:", default)?
+                    .map(|v| split_list(&v))
+                    .unwrap_or_default()
+            }
+            tickets => tickets,
+        };
 
         let scope = self
             .scope
             .clone()
             .or_else_try(|| prompt.text("Scope:", branch.clone().and_then(|b| b.scope)))?;
 
-        let link = self
-            .link
-            .clone()
-            .or_else_try(|| prompt.text("Link:", branch.and_then(|b| b.link)))?;
+        // Links are rendered from the ticket when an issue tracker url pattern is configured.
+        let links = match self.link.is_empty() && ticket_link.is_none() {
+            true => prompt
+                .text("Link:", branch.as_ref().and_then(links_default))?
+                .map(|v| split_links(&v))
+                .unwrap_or_default(),
+            false => self.link.clone(),
+        };
 
         Ok(Context {
-            ticket,
+            tickets,
             scope,
-            link,
+            links,
         })
     }
 
@@ -57,47 +107,66 @@ impl Arguments {
         prompt: P,
         interactive: &Interactive,
         branch: Option<Branch>,
+        ticket_link: Option<&str>,
     ) -> Result<Context, UserInputError> {
         let domain = match interactive {
-            Interactive::Enable => self.try_prompt_with_defaults(branch, prompt)?,
+            Interactive::Enable => self.try_prompt_with_defaults(branch, prompt, ticket_link)?,
             Interactive::Disable => Context {
-                ticket: self.ticket.clone(),
+                tickets: self.all_tickets(),
                 scope: self.scope.clone(),
-                link: self.link.clone(),
+                links: self.link.clone(),
             },
         };
 
         Ok(domain)
     }
+
+    /// Positional ticket followed by any repeated `--ticket` options.
+    fn all_tickets(&self) -> Vec<String> {
+        self.ticket
+            .iter()
+            .chain(self.tickets.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+/// Every stored link in ticket order separated by spaces.
+fn links_default(branch: &Branch) -> Option<String> {
+    let links = branch
+        .all_tickets()
+        .into_iter()
+        .filter_map(|t| t.link)
+        .collect::<Vec<_>>();
+
+    (!links.is_empty()).then(|| links.join(" "))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anyhow::Context as _;
     use fake::{Fake, Faker};
 
-    use crate::domain::{
-        adapters::prompt::SelectItem, commands::context::Context, errors::UserInputError,
-    };
+    use crate::{cli::prompt_test::PromptTest, domain::commands::context::Context};
 
     #[test]
     fn try_into_domain_with_no_interactive_prompts() -> anyhow::Result<()> {
         let args = fake_args();
 
         let prompt = PromptTest {
-            select_index: Err(anyhow::anyhow!("select should not be called")),
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
         };
 
         let actual = args
             .clone()
-            .try_into_domain(prompt, &Interactive::Disable, None)?;
+            .try_into_domain(prompt, &Interactive::Disable, None, None)?;
 
         let expected = Context {
-            ticket: args.ticket.clone(),
+            tickets: args.ticket.iter().chain(&args.tickets).cloned().collect(),
             scope: args.scope.clone(),
-            link: args.link.clone(),
+            links: args.link.clone(),
         };
 
         assert_eq!(expected, actual);
@@ -108,27 +177,29 @@ mod tests {
     #[test]
     fn try_into_domain_with_interactive_prompt_is_used_if_none() -> anyhow::Result<()> {
         let args = Arguments {
+            action: None,
             ticket: None,
+            tickets: vec![],
             scope: None,
-            link: None,
-            ..fake_args()
+            link: vec![],
         };
 
-        let text_prompt = Faker.fake::<Option<String>>();
+        let text_prompt = Some("ABC-1, ABC-2".to_string());
 
         let prompt = PromptTest {
-            select_index: Err(anyhow::anyhow!("select should not be called")),
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Ok(text_prompt.clone()),
+            ..PromptTest::default()
         };
 
         let actual = args
             .clone()
-            .try_into_domain(prompt, &Interactive::Enable, None)?;
+            .try_into_domain(prompt, &Interactive::Enable, None, None)?;
 
         let expected = Context {
-            ticket: text_prompt.clone(),
+            tickets: vec!["ABC-1".into(), "ABC-2".into()],
             scope: text_prompt.clone(),
-            link: text_prompt.clone(),
+            links: vec!["ABC-1,".into(), "ABC-2".into()],
         };
 
         assert_eq!(expected, actual);
@@ -140,24 +211,27 @@ mod tests {
     fn try_into_domain_with_interactive_prompt_is_not_used_if_value_is_already_provided(
     ) -> anyhow::Result<()> {
         let args = Arguments {
-            ticket: Some(Faker.fake()),
+            action: None,
+            ticket: Some("ABC-1".into()),
+            tickets: vec!["ABC-2".into()],
             scope: Some(Faker.fake()),
-            link: Some(Faker.fake()),
+            link: vec![Faker.fake()],
         };
 
         let prompt = PromptTest {
-            select_index: Err(anyhow::anyhow!("select should not be called")),
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
             text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
         };
 
         let actual = args
             .clone()
-            .try_into_domain(prompt, &Interactive::Enable, None)?;
+            .try_into_domain(prompt, &Interactive::Enable, None, None)?;
 
         let expected = Context {
-            ticket: args.ticket.clone(),
+            tickets: vec!["ABC-1".into(), "ABC-2".into()],
             scope: args.scope.clone(),
-            link: args.link.clone(),
+            links: args.link.clone(),
         };
 
         assert_eq!(expected, actual);
@@ -165,47 +239,62 @@ mod tests {
         Ok(())
     }
 
-    pub struct PromptTest {
-        select_index: anyhow::Result<usize>,
-        text_result: anyhow::Result<Option<String>>,
-    }
+    #[test]
+    fn try_into_domain_with_ticket_link_pattern_does_not_prompt_for_link() -> anyhow::Result<()> {
+        let args = Arguments {
+            action: None,
+            ticket: Some(Faker.fake()),
+            tickets: vec![],
+            scope: Some(Faker.fake()),
+            link: vec![],
+        };
 
-    impl Prompter for PromptTest {
-        fn text(&self, name: &str, _: Option<String>) -> Result<Option<String>, UserInputError> {
-            match &self.text_result {
-                Ok(option) => Ok(option.clone()),
-                Err(_) => Err(UserInputError::Validation {
-                    name: name.into(),
-                    message: "An error occurred within the mock prompter".into(),
-                }),
-            }
-        }
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+            ..PromptTest::default()
+        };
 
-        fn select<T>(
-            &self,
-            name: &str,
-            options: Vec<SelectItem<T>>,
-        ) -> Result<SelectItem<T>, UserInputError> {
-            match &self.select_index {
-                Ok(index) => options
-                    .into_iter()
-                    .nth(index.clone())
-                    .context("Failed to get item")
-                    .map_err(|_| UserInputError::Validation {
-                        name: name.into(),
-                        message: "An error occurred within the mock prompter".into(),
-                    }),
-                Err(_) => Err(UserInputError::Validation {
-                    name: name.into(),
-                    message: "An error occurred within the mock prompter".into(),
-                }),
-            }
-        }
+        let actual = args.clone().try_into_domain(
+            prompt,
+            &Interactive::Enable,
+            None,
+            Some("https://tracker/browse/{ticket_num}"),
+        )?;
+
+        assert!(actual.links.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn links_default_includes_every_ticket_link() {
+        let branch = Branch::with_tickets(
+            "name",
+            "repo",
+            vec!["ABC-1".into(), "ABC-2".into()],
+            vec![
+                "https://tracker/ABC-1".into(),
+                "https://tracker/ABC-2".into(),
+            ],
+            None,
+        );
+
+        assert_eq!(
+            Some("https://tracker/ABC-1 https://tracker/ABC-2".into()),
+            links_default(&branch)
+        );
+        assert_eq!(
+            None,
+            links_default(&Branch::new("name", "repo", None, None, None))
+        );
     }
 
     fn fake_args() -> Arguments {
         Arguments {
+            action: None,
             ticket: Faker.fake(),
+            tickets: Faker.fake(),
             scope: Faker.fake(),
             link: Faker.fake(),
         }
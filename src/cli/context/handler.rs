@@ -1,29 +1,77 @@
+use colored::Colorize;
+
 use crate::{
     app_context::AppContext,
     domain::{
         adapters::{prompt::Prompter, Git, Store},
         commands::context,
         errors::Errors,
+        models::{render_ticket_link, Branch},
     },
+    template_config::TemplateConfig,
 };
 
-use super::Arguments;
+use super::{Action, Arguments};
 
 pub fn handler<G: Git, S: Store, P: Prompter>(
     context: &AppContext<G, S>,
     args: Arguments,
     prompt: P,
 ) -> Result<(), Errors> {
-    let repo_name = context.git.repository_name().map_err(Errors::Git)?;
-    let branch_name = context.git.branch_name().map_err(Errors::Git)?;
+    let templates = TemplateConfig::new(&context.config.path)?;
+    let ticket_link = templates.commit.ticket_link.as_deref();
+
+    match args.action.clone() {
+        Some(Action::Set { key, value }) => {
+            context::set(&context.git, &context.store, &key, &value)?;
+            println!("Set {} to '{value}'.", key.bold());
+        }
+        Some(Action::Unset { key }) => {
+            match context::unset(&context.git, &context.store, &key)? {
+                true => println!("Unset {}.", key.bold()),
+                false => println!("No value set for {}.", key.bold()),
+            };
+        }
+        Some(Action::Show) => match context::show(&context.git, &context.store)? {
+            Some(branch) => show(&branch, ticket_link),
+            None => println!("No context stored for the current branch."),
+        },
+        None => {
+            let repo_name = context.git.repository_name().map_err(Errors::Git)?;
+            let branch_name = context.git.branch_name().map_err(Errors::Git)?;
 
-    let branch = context.store.get_branch(&branch_name, &repo_name).ok();
+            let branch = context.store.get_branch(&branch_name, &repo_name).ok();
 
-    let args = args
-        .try_into_domain(prompt, &context.interactive, branch)
-        .map_err(Errors::UserInput)?;
+            let args = args
+                .try_into_domain(prompt, &context.interactive, branch, ticket_link)
+                .map_err(Errors::UserInput)?;
 
-    context::handler(&context.git, &context.store, args)?;
+            let branch = context::handler(&context.git, &context.store, args)?;
+
+            if let Some(link) = branch.ticket_link(ticket_link) {
+                println!("Ticket link: {link}");
+            }
+        }
+    }
 
     Ok(())
 }
+
+fn show(branch: &Branch, ticket_link: Option<&str>) {
+    for ticket in branch.all_tickets() {
+        let link = ticket
+            .link
+            .or_else(|| ticket_link.map(|pattern| render_ticket_link(pattern, &ticket.ticket)));
+
+        let link = link.map(|l| format!(" {}", l.dimmed())).unwrap_or_default();
+        println!("{:<10} {}{link}", "ticket", ticket.ticket.bold());
+    }
+
+    if let Some(scope) = &branch.scope {
+        println!("{:<10} {scope}", "scope");
+    }
+
+    for (key, value) in &branch.data {
+        println!("{key:<10} {value}");
+    }
+}
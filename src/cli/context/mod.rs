@@ -1,5 +1,5 @@
 mod args;
 mod handler;
 
-pub use args::Arguments;
+pub use args::{Action, Arguments};
 pub use handler::handler;
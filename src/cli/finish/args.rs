@@ -0,0 +1,44 @@
+use clap::Args;
+
+#[derive(Debug, Clone, Args)]
+pub struct Arguments {
+    /// Branch to merge into, defaults to 'finish.base' in the config or the remote's default branch.
+    #[clap(long, value_parser)]
+    pub base: Option<String>,
+
+    /// Squash the branch into a single commit rather than creating a merge commit.
+    #[clap(long, overrides_with = "no_squash")]
+    pub squash: bool,
+
+    /// Create a merge commit even when squashing is configured.
+    #[clap(long, overrides_with = "squash")]
+    pub no_squash: bool,
+
+    /// Delete the branch once merged.
+    #[clap(long, overrides_with = "no_delete")]
+    pub delete: bool,
+
+    /// Keep the branch once merged.
+    #[clap(long, overrides_with = "delete")]
+    pub no_delete: bool,
+}
+
+impl Arguments {
+    /// Explicit choice of whether to squash, otherwise the configured default is used.
+    pub fn squash(&self) -> Option<bool> {
+        flag(self.squash, self.no_squash)
+    }
+
+    /// Explicit choice of whether to delete the branch, otherwise the configured default is used.
+    pub fn delete(&self) -> Option<bool> {
+        flag(self.delete, self.no_delete)
+    }
+}
+
+fn flag(enable: bool, disable: bool) -> Option<bool> {
+    match (enable, disable) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    }
+}
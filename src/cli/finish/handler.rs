@@ -0,0 +1,75 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{prompt::Prompter, Git, MergeStrategy, Store},
+        commands::finish::{self, Finish},
+        errors::Errors,
+    },
+    entry::Interactive,
+    template_config::TemplateConfig,
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store, P: Prompter>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+    prompt: P,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let base = match args.base.clone().or(templates.finish.base.clone()) {
+        Some(base) => base,
+        // The remote default branch is merged into via its local branch.
+        None => {
+            let default = context.git.default_branch().map_err(Errors::Git)?;
+            default
+                .strip_prefix("origin/")
+                .map(String::from)
+                .unwrap_or(default)
+        }
+    };
+
+    let strategy = match args.squash().unwrap_or(templates.finish.squash) {
+        true => MergeStrategy::Squash,
+        false => MergeStrategy::Merge,
+    };
+
+    let delete = match (args.delete(), &context.interactive) {
+        (Some(delete), _) => delete,
+        (None, Interactive::Enable) => prompt
+            .confirm("Delete the branch once merged", templates.finish.delete)
+            .map_err(Errors::UserInput)?,
+        (None, Interactive::Disable) => templates.finish.delete,
+    };
+
+    let finished = finish::handler(
+        &context.git,
+        &context.store,
+        &templates,
+        Finish {
+            base: base.clone(),
+            strategy,
+            delete,
+        },
+    )?;
+
+    let action = match strategy {
+        MergeStrategy::Merge => "Merged",
+        MergeStrategy::Squash => "Squashed",
+    };
+    println!(
+        "{} {} into {}.",
+        action,
+        finished.branch.bold(),
+        base.bold().green()
+    );
+
+    if let Some(branch) = finished.context {
+        println!("Archived context for ticket {}.", branch.ticket.bold());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use clap::clap_derive::ArgEnum;
+use serde::Serialize;
+
+use crate::domain::errors::Errors;
+
+/// Output format for generated reports.
+#[derive(Debug, Clone, PartialEq, Eq, ArgEnum)]
+pub enum Format {
+    Markdown,
+    Json,
+}
+
+impl Format {
+    pub fn render<T: Serialize, F: FnOnce(&T) -> String>(
+        &self,
+        value: &T,
+        markdown: F,
+    ) -> Result<String, Errors> {
+        match self {
+            Format::Markdown => Ok(markdown(value)),
+            Format::Json => {
+                serde_json::to_string_pretty(value).map_err(|e| Errors::ValidationError {
+                    message: format!("Failed to serialize output {e}"),
+                })
+            }
+        }
+    }
+}
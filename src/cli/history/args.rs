@@ -0,0 +1,8 @@
+use clap::Args;
+
+#[derive(Debug, Clone, Args)]
+pub struct Arguments {
+    /// Maximum number of rendered commit messages to display.
+    #[clap(short, long, value_parser, default_value_t = 10)]
+    pub limit: usize,
+}
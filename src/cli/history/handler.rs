@@ -0,0 +1,61 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        errors::Errors,
+        models::{CommitHistory, CommitStatus},
+    },
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let repo_name = context.git.repository_name().map_err(Errors::Git)?;
+    let branch_name = context.git.branch_name().map_err(Errors::Git)?;
+
+    log::info!("collect commit history for branch '{}'", branch_name);
+    let history = context
+        .store
+        .get_history(&branch_name, &repo_name, args.limit)
+        .map_err(Errors::PersistError)?;
+
+    if history.is_empty() {
+        println!("No commit history found for branch '{}'.", branch_name);
+    }
+
+    for entry in history {
+        print_entry(&entry);
+    }
+
+    Ok(())
+}
+
+fn print_entry(history: &CommitHistory) {
+    let status = match history.status {
+        CommitStatus::Committed => "✔ committed".green(),
+        CommitStatus::Failed => "✘ failed".red(),
+        CommitStatus::Pending => "… pending".yellow(),
+    };
+
+    println!(
+        "{} {} {} {}",
+        status,
+        history
+            .created
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+            .dimmed(),
+        history.template.bold(),
+        history.id[..8].dimmed()
+    );
+
+    for line in history.message.lines() {
+        println!("    {}", line);
+    }
+    println!();
+}
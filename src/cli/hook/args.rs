@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+
+#[allow(clippy::almost_swapped)]
+#[allow(clippy::correctness)]
+#[derive(Debug, Clone, Subcommand)]
+pub enum Arguments {
+    /// Rewrite git's default merge commit message through the 'merge' template.
+    PrepareCommitMsg(PrepareCommitMsg),
+}
+
+/// Arguments git passes to the 'prepare-commit-msg' hook.
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct PrepareCommitMsg {
+    /// File containing the commit message.
+    #[clap(value_parser)]
+    pub file: PathBuf,
+
+    /// Source of the commit message i.e. 'merge' or 'message'.
+    #[clap(value_parser)]
+    pub source: Option<String>,
+
+    /// Commit being amended, if any.
+    #[clap(value_parser)]
+    pub commit: Option<String>,
+}
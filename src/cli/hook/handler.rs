@@ -0,0 +1,48 @@
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::hook,
+        errors::Errors,
+    },
+    template_config::TemplateConfig,
+};
+
+use super::{args::PrepareCommitMsg, Arguments};
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    match args {
+        Arguments::PrepareCommitMsg(args) => prepare_commit_msg(context, &templates, args),
+    }
+}
+
+fn prepare_commit_msg<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    templates: &TemplateConfig,
+    args: PrepareCommitMsg,
+) -> Result<(), Errors> {
+    let content = std::fs::read_to_string(&args.file).map_err(|_| Errors::ValidationError {
+        message: "Failed attempting to read commit message file".into(),
+    })?;
+
+    let message = hook::prepare_commit_msg(
+        &context.git,
+        &context.store,
+        templates,
+        &content,
+        args.source.as_deref(),
+    )?;
+
+    if let Some(message) = message {
+        std::fs::write(&args.file, message).map_err(|_| Errors::ValidationError {
+            message: "Failed attempting to write commit message file".into(),
+        })?;
+    }
+
+    Ok(())
+}
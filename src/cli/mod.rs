@@ -1,8 +1,24 @@
+pub mod apply_ticket;
+pub mod branch;
+pub mod changelog;
 pub mod checkout;
 pub mod commands;
 pub mod commit;
+pub mod commit_log;
 pub mod config;
 pub mod context;
 pub mod error;
+pub mod finish;
+pub mod format;
+pub mod history;
+pub mod hook;
 pub mod log;
+pub mod open;
+#[cfg(test)]
+pub mod prompt_test;
+pub mod release_notes;
+pub mod reword;
+pub mod switch;
+pub mod tag;
 pub mod templates;
+pub mod version;
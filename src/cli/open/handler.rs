@@ -0,0 +1,26 @@
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::open,
+        errors::Errors,
+    },
+    template_config::TemplateConfig,
+    utils::browser,
+};
+
+pub fn handler<G: Git, S: Store>(context: &AppContext<G, S>) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let link = open::handler(
+        &context.git,
+        &context.store,
+        templates.commit.ticket_link.as_deref(),
+    )?;
+
+    println!("Opening {link}");
+
+    browser::open(&link).map_err(|e| Errors::ValidationError {
+        message: format!("Failed to open ticket link in browser, {e}"),
+    })
+}
@@ -0,0 +1,81 @@
+use anyhow::Context;
+
+use crate::domain::{
+    adapters::prompt::{Prompter, SelectItem},
+    errors::UserInputError,
+};
+
+/// Prompter returning canned answers, any prompt without one errors.
+pub struct PromptTest {
+    pub select_item_name: anyhow::Result<String>,
+    pub text_result: anyhow::Result<Option<String>>,
+    pub editor_result: anyhow::Result<Option<String>>,
+    pub multi_select_names: anyhow::Result<Vec<String>>,
+}
+
+impl Default for PromptTest {
+    fn default() -> Self {
+        PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+            editor_result: Err(anyhow::anyhow!("editor should not be called")),
+            multi_select_names: Err(anyhow::anyhow!("multi_select should not be called")),
+        }
+    }
+}
+
+fn prompt_error(name: &str) -> UserInputError {
+    UserInputError::Validation {
+        name: name.into(),
+        message: "An error occurred within the mock prompter".into(),
+    }
+}
+
+impl Prompter for PromptTest {
+    fn text(&self, name: &str, _: Option<String>) -> Result<Option<String>, UserInputError> {
+        match &self.text_result {
+            Ok(option) => Ok(option.clone()),
+            Err(_) => Err(prompt_error(name)),
+        }
+    }
+
+    fn editor(&self, name: &str, _: Option<String>) -> Result<Option<String>, UserInputError> {
+        match &self.editor_result {
+            Ok(option) => Ok(option.clone()),
+            Err(_) => Err(prompt_error(name)),
+        }
+    }
+
+    fn confirm(&self, name: &str, _: bool) -> Result<bool, UserInputError> {
+        Err(prompt_error(name))
+    }
+
+    fn select<T>(
+        &self,
+        name: &str,
+        options: Vec<SelectItem<T>>,
+    ) -> Result<SelectItem<T>, UserInputError> {
+        match &self.select_item_name {
+            Ok(selected) => options
+                .into_iter()
+                .find(|item| &item.name == selected)
+                .context("Failed to get item")
+                .map_err(|_| prompt_error(name)),
+            Err(_) => Err(prompt_error(name)),
+        }
+    }
+
+    fn multi_select<T>(
+        &self,
+        name: &str,
+        options: Vec<SelectItem<T>>,
+    ) -> Result<Vec<SelectItem<T>>, UserInputError> {
+        match &self.multi_select_names {
+            Ok(names) => Ok(options
+                .into_iter()
+                .filter(|item| names.contains(&item.name))
+                .collect()),
+            Err(_) => Err(prompt_error(name)),
+        }
+    }
+}
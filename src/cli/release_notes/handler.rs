@@ -0,0 +1,27 @@
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::release_notes,
+        errors::Errors,
+    },
+    template_config::TemplateConfig,
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let notes =
+        release_notes::handler(&context.git, &context.store, &templates.commit, &args.range)?;
+
+    let output = args.format.render(&notes, |n| n.to_markdown())?;
+
+    println!("{output}");
+
+    Ok(())
+}
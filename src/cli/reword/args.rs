@@ -0,0 +1,232 @@
+use clap::Args;
+
+use crate::{
+    cli::commit,
+    domain::{
+        adapters::prompt::Prompter, commands::commit::Commit, errors::UserInputError,
+        parser::ParsedCommit,
+    },
+    entry::Interactive,
+    template_config::TemplateConfig,
+};
+
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct Arguments {
+    /// Revision of the commit to reword i.e. `HEAD~2`.
+    #[clap(value_parser, default_value = "HEAD")]
+    pub rev: String,
+
+    /// Name of the commit template to be used, defaults to the template matching the current message.
+    #[clap(long, value_parser)]
+    pub template: Option<String>,
+
+    /// Issue ticket number related to the commit.
+    #[clap(short, long, value_parser)]
+    pub ticket: Option<String>,
+
+    /// Message for the commit.
+    #[clap(short, long, value_parser)]
+    pub message: Option<String>,
+
+    /// Short describing a section of the codebase the changes relate to.
+    #[clap(short, long, value_parser)]
+    pub scope: Option<String>,
+}
+
+impl Arguments {
+    /// Values parsed from the existing message are used as defaults for any inputs not provided.
+    pub fn try_into_domain<P: Prompter>(
+        &self,
+        config: &TemplateConfig,
+        prompter: P,
+        interactive: &Interactive,
+        parsed: ParsedCommit,
+    ) -> Result<Commit, UserInputError> {
+        let suggested = Some(parsed.template.clone()).filter(|t| !t.is_empty());
+
+        let template_name = match (&self.template, interactive) {
+            (Some(template), _) => template.clone(),
+            (None, Interactive::Enable) => {
+                let items = commit::Arguments::template_select_items(
+                    config.commit.templates.clone(),
+                    suggested,
+                );
+                prompter.select("Template", items)?.name
+            }
+            (None, Interactive::Disable) => suggested.ok_or_else(|| UserInputError::Required {
+                name: "template".into(),
+            })?,
+        };
+
+        let template = config.get_template_config(&template_name)?.clone();
+
+        let prompt = |name: &str,
+                      value: &Option<String>,
+                      default: Option<String>,
+                      question: &str| {
+            match (value, interactive, template.contains_placeholder(name)) {
+                (Some(value), ..) => Ok(Some(value.clone())),
+                (None, Interactive::Enable, true) => prompter.text(question, default),
+                (None, ..) => Ok(default),
+            }
+        };
+
+        let ticket = prompt("ticket_num", &self.ticket, parsed.ticket, "Ticket")?;
+        let scope = prompt("scope", &self.scope, parsed.scope, "Scope")?;
+        let message = prompt("message", &self.message, parsed.message, "Message")?;
+
+        let body = match (interactive, template.contains_placeholder("body")) {
+            (Interactive::Enable, true) => prompter.editor("Body", parsed.body)?,
+            _ => parsed.body,
+        };
+
+        Ok(Commit {
+            template_name,
+            template,
+            ticket,
+            message,
+            scope,
+            body,
+            footers: vec![],
+            ticket_link: config.commit.ticket_link.clone(),
+            ticket_separator: config.commit.ticket_separator.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        cli::prompt_test::PromptTest,
+        template_config::{CommitConfig, Template},
+    };
+
+    #[test]
+    fn try_into_domain_without_prompts_defaults_to_parsed_values() -> anyhow::Result<()> {
+        let args = Arguments {
+            ticket: Some("ABC-2".into()),
+            ..fake_args()
+        };
+
+        let parsed = ParsedCommit {
+            template: "bug".into(),
+            ticket: Some("ABC-1".into()),
+            message: Some("Fix".into()),
+            body: Some("Details".into()),
+            ..ParsedCommit::default()
+        };
+
+        let actual = args.try_into_domain(
+            &fake_config(),
+            PromptTest::default(),
+            &Interactive::Disable,
+            parsed,
+        )?;
+
+        assert_eq!("bug", actual.template_name);
+        assert_eq!(Some("ABC-2".into()), actual.ticket);
+        assert_eq!(Some("Fix".into()), actual.message);
+        assert_eq!(Some("Details".into()), actual.body);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_without_prompts_or_parsed_template_errors() {
+        let result = fake_args().try_into_domain(
+            &fake_config(),
+            PromptTest::default(),
+            &Interactive::Disable,
+            ParsedCommit::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(UserInputError::Required { name }) if name == "template"
+        ));
+    }
+
+    #[test]
+    fn try_into_domain_with_unmatched_message_keeps_subject_and_body() -> anyhow::Result<()> {
+        let args = Arguments {
+            template: Some("bug".into()),
+            ticket: Some("ABC-1".into()),
+            ..fake_args()
+        };
+
+        let parsed =
+            ParsedCommit::unmatched("Handle null session token\n\nLong explanation of root cause.");
+
+        let actual = args.try_into_domain(
+            &fake_config(),
+            PromptTest::default(),
+            &Interactive::Disable,
+            parsed,
+        )?;
+
+        assert_eq!(Some("Handle null session token".into()), actual.message);
+        assert_eq!(Some("Long explanation of root cause.".into()), actual.body);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_prompts_selects_template_and_prompts_for_placeholders(
+    ) -> anyhow::Result<()> {
+        let parsed = ParsedCommit {
+            template: "bug".into(),
+            ticket: Some("ABC-1".into()),
+            message: Some("Fix".into()),
+            ..ParsedCommit::default()
+        };
+
+        let prompt = PromptTest {
+            select_item_name: Ok("feat".into()),
+            text_result: Ok(Some("Prompted".into())),
+            ..PromptTest::default()
+        };
+
+        let actual =
+            fake_args().try_into_domain(&fake_config(), prompt, &Interactive::Enable, parsed)?;
+
+        assert_eq!("feat", actual.template_name);
+        assert_eq!(Some("Prompted".into()), actual.ticket);
+        assert_eq!(Some("Prompted".into()), actual.message);
+        // Template has no scope placeholder so it isn't prompted for.
+        assert_eq!(None, actual.scope);
+
+        Ok(())
+    }
+
+    fn fake_config() -> TemplateConfig {
+        let template = |content: &str| Template {
+            description: "description".into(),
+            content: content.into(),
+            footers: vec![],
+        };
+
+        TemplateConfig {
+            commit: CommitConfig {
+                templates: HashMap::from([
+                    ("bug".into(), template("[{ticket_num}] 🐛 {message}")),
+                    ("feat".into(), template("[{ticket_num}] ✨ {message}")),
+                ]),
+                ..CommitConfig::default()
+            },
+            ..TemplateConfig::default()
+        }
+    }
+
+    fn fake_args() -> Arguments {
+        Arguments {
+            rev: "HEAD".into(),
+            template: None,
+            ticket: None,
+            message: None,
+            scope: None,
+        }
+    }
+}
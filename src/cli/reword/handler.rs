@@ -0,0 +1,45 @@
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{prompt::Prompter, Git, Store},
+        commands::reword,
+        errors::Errors,
+        parser::{CommitParser, ParsedCommit},
+    },
+    template_config::TemplateConfig,
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store, P: Prompter>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+    prompter: P,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let parser =
+        CommitParser::new(&templates.commit.templates).map_err(|e| Errors::Configuration {
+            message: "Failed to parse commit templates".into(),
+            source: e,
+        })?;
+
+    let target = context.git.show(&args.rev).map_err(Errors::Git)?;
+    let parsed = parser
+        .parse(&target.message)
+        .unwrap_or_else(|| ParsedCommit::unmatched(&target.message));
+    let (ticket, link) = (parsed.ticket.clone(), parsed.link.clone());
+
+    let commit = args
+        .try_into_domain(&templates, prompter, &context.interactive, parsed)
+        .map_err(Errors::UserInput)?;
+
+    // Parsed link belongs to the original ticket, a new ticket's link comes from the `ticket_link` pattern.
+    let link = link.filter(|_| commit.ticket == ticket);
+
+    let message = reword::handler(&context.git, &target, commit, link)?;
+
+    println!("{message}");
+
+    Ok(())
+}
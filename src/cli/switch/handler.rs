@@ -0,0 +1,60 @@
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{
+            prompt::{Prompter, SelectItem},
+            Git, Store,
+        },
+        commands::switch::{self, Switch},
+        errors::{Errors, UserInputError},
+    },
+    entry::Interactive,
+};
+
+pub fn handler<G: Git, S: Store, P: Prompter>(
+    context: &AppContext<G, S>,
+    prompt: P,
+) -> Result<(), Errors> {
+    if context.interactive == Interactive::Disable {
+        return Err(Errors::UserInput(UserInputError::Required {
+            name: "branch".into(),
+        }));
+    }
+
+    let branches = switch::branches(&context.git, &context.store)?;
+
+    if branches.is_empty() {
+        println!("No other local branches to switch to.");
+        return Ok(());
+    }
+
+    let options = branches.into_iter().map(select_item).collect();
+    let selected = prompt
+        .select("Branch", options)
+        .map_err(Errors::UserInput)?;
+
+    switch::handler(&context.git, &context.store, &selected.value)?;
+
+    Ok(())
+}
+
+/// Branch name along with any stored ticket, scope & when it was last used.
+fn select_item(switch: Switch) -> SelectItem<String> {
+    let description = switch.context.map(|branch| {
+        let mut details = vec![branch.ticket];
+        details.extend(branch.scope);
+        details.extend(
+            branch
+                .last_used
+                .map(|date| format!("used {}", date.format("%Y-%m-%d %H:%M"))),
+        );
+
+        details.join(" · ")
+    });
+
+    SelectItem {
+        name: switch.name.clone(),
+        value: switch.name,
+        description,
+    }
+}
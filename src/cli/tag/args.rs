@@ -0,0 +1,12 @@
+use clap::Args;
+
+#[derive(Debug, Clone, Args)]
+pub struct Arguments {
+    /// Version to tag i.e. 'v1.2.3', defaults to the suggested next version.
+    #[clap(value_parser)]
+    pub version: Option<String>,
+
+    /// Message for the annotated tag, defaults to the 'tag' template in the config.
+    #[clap(short, long, value_parser)]
+    pub message: Option<String>,
+}
@@ -0,0 +1,47 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::tag::{self, Tag},
+        errors::Errors,
+        models::Version,
+    },
+    template_config::TemplateConfig,
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    let version = args
+        .version
+        .map(|version| {
+            Version::parse(&version).ok_or_else(|| Errors::ValidationError {
+                message: format!("'{version}' isn't a semantic version i.e. 'v1.2.3'"),
+            })
+        })
+        .transpose()?;
+
+    let tagged = tag::handler(
+        &context.git,
+        &templates,
+        Tag {
+            version,
+            message: args.message,
+        },
+    )?;
+
+    let version = tagged.version.to_string().bold().green();
+    match tagged.previous {
+        Some(previous) => println!("Created tag {version} following {previous}."),
+        None => println!("Created tag {version}."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use clap::{Args, Subcommand};
+
+#[allow(clippy::almost_swapped)]
+#[allow(clippy::correctness)]
+#[derive(Debug, Clone, Subcommand)]
+pub enum Arguments {
+    /// Suggest the next version from commits since the latest version tag.
+    Next(VersionNext),
+}
+
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct VersionNext {
+    /// Create an annotated tag for the suggested version on HEAD.
+    #[clap(long)]
+    pub tag: bool,
+
+    /// Message for the annotated tag, defaults to the 'tag' template in the config.
+    #[clap(short, long, value_parser, requires = "tag")]
+    pub message: Option<String>,
+}
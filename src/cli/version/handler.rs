@@ -0,0 +1,72 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::{
+            tag::{self, Tag},
+            version,
+        },
+        errors::Errors,
+    },
+    template_config::TemplateConfig,
+};
+
+use super::{args::VersionNext, Arguments};
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let templates = TemplateConfig::new(&context.config.path)?;
+
+    match args {
+        Arguments::Next(args) => next(context, &templates, args),
+    }
+}
+
+fn next<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    templates: &TemplateConfig,
+    args: VersionNext,
+) -> Result<(), Errors> {
+    let suggestion = version::next(&context.git, &templates.commit)?;
+
+    let current = suggestion
+        .current
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "no version".into());
+
+    let (Some(next), Some(bump)) = (&suggestion.next, suggestion.bump) else {
+        println!(
+            "No release needed, none of the {} commits since {} warrant a version bump.",
+            suggestion.commits,
+            current.bold()
+        );
+        return Ok(());
+    };
+
+    println!(
+        "{} → {} ({} from {} commits)",
+        current,
+        next.to_string().bold().green(),
+        bump,
+        suggestion.commits
+    );
+
+    if args.tag {
+        tag::handler(
+            &context.git,
+            templates,
+            Tag {
+                version: Some(next.clone()),
+                message: args.message,
+            },
+        )?;
+        println!("Created tag {}.", next.to_string().bold());
+    }
+
+    Ok(())
+}
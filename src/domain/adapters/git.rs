@@ -1,11 +1,43 @@
-use std::path::{Path, PathBuf};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
 
-use crate::domain::{errors::GitError, models::path::AbsolutePath};
+use crate::domain::{
+    errors::GitError,
+    models::{path::AbsolutePath, GitCommit},
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum CheckoutStatus {
-    New,
+    /// Create a new branch from HEAD or the given start point.
+    New {
+        from: Option<String>,
+    },
     Existing,
+    /// Create a new branch tracking a remote branch i.e. `origin/feat`.
+    Track {
+        upstream: String,
+    },
+}
+
+/// Where an existing branch can be found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BranchLocation {
+    Local,
+    /// Only exists on a remote i.e. `origin/feat`.
+    Remote {
+        upstream: String,
+    },
+}
+
+/// How a branch is merged into the current branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Always create a merge commit, even when a fast-forward is possible.
+    Merge,
+    /// Combine the branch's changes into a single commit.
+    Squash,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -14,6 +46,28 @@ pub enum CommitMsgStatus {
     Completed,
 }
 
+/// Operation in progress that would conflict with a new commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitOperation {
+    Merge,
+    Rebase,
+    CherryPick,
+    Revert,
+}
+
+impl fmt::Display for GitOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            GitOperation::Merge => "merge",
+            GitOperation::Rebase => "rebase",
+            GitOperation::CherryPick => "cherry-pick",
+            GitOperation::Revert => "revert",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
 /// Used to abstract cli git commands for testing.
 pub trait Git {
     /// Get the root directory of the current git repo.
@@ -25,9 +79,70 @@ pub trait Git {
     /// Get the current checked out branch name.
     fn branch_name(&self) -> Result<String, GitError>;
 
-    /// Checkout an existing branch of create a new branch if not.
+    /// Checkout an existing branch or create a new branch.
     fn checkout(&self, name: &str, status: CheckoutStatus) -> Result<(), GitError>;
 
+    /// Find whether a branch exists locally or on a remote, preferring `origin`.
+    fn branch_location(&self, name: &str) -> Result<Option<BranchLocation>, GitError>;
+
+    /// Rename a local branch.
+    fn rename_branch(&self, from: &str, to: &str) -> Result<(), GitError>;
+
+    /// Get the names of all local branches.
+    fn branches(&self) -> Result<Vec<String>, GitError>;
+
+    /// Get branch renames recorded in the reflog as old & new name pairs, most recent first.
+    fn branch_renames(&self) -> Result<Vec<(String, String)>, GitError>;
+
+    /// Merge a branch into the current branch committing with the given message.
+    fn merge(&self, branch: &str, message: &str, strategy: MergeStrategy) -> Result<(), GitError>;
+
+    /// Delete a local branch, forced deletes don't require the branch to be merged.
+    fn delete_branch(&self, name: &str, force: bool) -> Result<(), GitError>;
+
+    /// Get the file paths of staged changes relative to the repository root.
+    fn staged_files(&self) -> Result<Vec<String>, GitError>;
+
+    /// Stage all changes to tracked files.
+    fn stage_tracked(&self) -> Result<(), GitError>;
+
+    /// Get any operation (i.e. merge, rebase) currently in progress.
+    fn operation_in_progress(&self) -> Result<Option<GitOperation>, GitError>;
+
+    /// Check if HEAD is detached from a branch.
+    fn is_detached(&self) -> Result<bool, GitError>;
+
+    /// Get commits within a revision range (i.e. `v1.0.0..HEAD`) most recent first, excluding merges.
+    /// Limited to the most recent `max_count` commits when provided.
+    fn log(&self, range: &str, max_count: Option<usize>) -> Result<Vec<GitCommit>, GitError>;
+
+    /// Check if a revision is HEAD or one of its ancestors.
+    fn is_ancestor(&self, rev: &str) -> Result<bool, GitError>;
+
+    /// Get the hashes of merge commits within a revision range (i.e. `main..HEAD`).
+    fn merges(&self, range: &str) -> Result<Vec<String>, GitError>;
+
+    /// Get a single commit by revision i.e. `HEAD~2`.
+    fn show(&self, rev: &str) -> Result<GitCommit, GitError>;
+
+    /// Replace the message of the HEAD commit without including any staged changes.
+    fn amend_message(&self, message: &str) -> Result<(), GitError>;
+
+    /// Replace commit messages by rewriting history, commit hash & message pairs are ordered most recent first.
+    fn reword(&self, messages: &[(String, String)]) -> Result<(), GitError>;
+
+    /// Get the best common ancestor of HEAD and another revision.
+    fn merge_base(&self, base: &str) -> Result<String, GitError>;
+
+    /// Get the branch the remote HEAD points to, otherwise `main`.
+    fn default_branch(&self) -> Result<String, GitError>;
+
+    /// Get tags reachable from HEAD ordered by version descending.
+    fn tags(&self) -> Result<Vec<String>, GitError>;
+
+    /// Create an annotated tag on HEAD.
+    fn create_tag(&self, name: &str, message: &str) -> Result<(), GitError>;
+
     /// Get the commit file path for the current repository.
     fn template_file_path(&self) -> Result<PathBuf, GitError>;
 
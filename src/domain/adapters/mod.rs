@@ -2,5 +2,8 @@ mod git;
 pub mod prompt;
 mod store;
 
-pub use git::{CheckoutStatus, CommitMsgStatus, Git, GitResult, GitSystem};
+pub use git::{
+    BranchLocation, CheckoutStatus, CommitMsgStatus, Git, GitOperation, GitResult, GitSystem,
+    MergeStrategy,
+};
 pub use store::Store;
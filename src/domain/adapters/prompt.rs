@@ -13,9 +13,24 @@ pub trait Prompter {
         default: Option<String>,
     ) -> Result<Option<String>, UserInputError>;
 
+    /// Multi-line text input opened within the users `$EDITOR`.
+    fn editor(
+        &self,
+        question: &str,
+        default: Option<String>,
+    ) -> Result<Option<String>, UserInputError>;
+
+    fn confirm(&self, question: &str, default: bool) -> Result<bool, UserInputError>;
+
     fn select<T>(
         &self,
         question: &str,
         options: Vec<SelectItem<T>>,
     ) -> Result<SelectItem<T>, UserInputError>;
+
+    fn multi_select<T>(
+        &self,
+        question: &str,
+        options: Vec<SelectItem<T>>,
+    ) -> Result<Vec<SelectItem<T>>, UserInputError>;
 }
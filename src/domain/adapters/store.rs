@@ -1,6 +1,6 @@
 use crate::domain::{
     errors::PersistError,
-    models::{Branch, Config, ConfigKey},
+    models::{Branch, CommitHistory, Config, ConfigKey},
 };
 
 pub trait Store {
@@ -8,6 +8,25 @@ pub trait Store {
 
     fn get_branch(&self, branch: &str, repo: &str) -> Result<Branch, PersistError>;
 
+    /// Get all stored branches for a repository ordered by most recently created.
+    fn get_branches(&self, repo: &str) -> Result<Vec<Branch>, PersistError>;
+
+    /// Move a branch's stored context & commit history to a new branch name within a single transaction.
+    fn rename_branch(&mut self, from: &str, to: &str, repo: &str) -> Result<Branch, PersistError>;
+
+    /// Mark a branch's stored context as archived, keeping it available to reports.
+    fn archive_branch(&self, branch: &str, repo: &str) -> Result<Branch, PersistError>;
+
+    fn persist_history(&self, history: &CommitHistory) -> Result<(), PersistError>;
+
+    /// Get rendered commit messages for a branch ordered by most recently created.
+    fn get_history(
+        &self,
+        branch: &str,
+        repo: &str,
+        limit: usize,
+    ) -> Result<Vec<CommitHistory>, PersistError>;
+
     fn persist_config(&self, config: &Config) -> Result<(), PersistError>;
 
     fn set_active_config(&mut self, key: &ConfigKey) -> Result<Config, PersistError>;
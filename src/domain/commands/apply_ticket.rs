@@ -0,0 +1,108 @@
+use crate::{
+    domain::{
+        adapters::{Git, Store},
+        commands::{commit::Commit, reword},
+        errors::{Errors, GitError, PersistError},
+        parser::CommitParser,
+    },
+    template_config::CommitConfig,
+};
+
+/// Commit message before & after the ticket was applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TicketChange {
+    pub hash: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Insert the branch ticket into commits since the merge base that are missing one.
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    config: &CommitConfig,
+    base: &str,
+    dry_run: bool,
+) -> Result<Vec<TicketChange>, Errors> {
+    if let Some(operation) = git.operation_in_progress().map_err(Errors::Git)? {
+        return Err(Errors::Git(GitError::InProgress { operation }));
+    }
+
+    if git.is_detached().map_err(Errors::Git)? {
+        return Err(Errors::Git(GitError::DetachedHead));
+    }
+
+    let parser = CommitParser::new(&config.templates).map_err(|e| Errors::Configuration {
+        message: "Failed to parse commit templates".into(),
+        source: e,
+    })?;
+
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let branch_name = git.branch_name().map_err(Errors::Git)?;
+
+    let branch = store
+        .get_branch(&branch_name, &repo_name)
+        .map_err(|e| match e {
+            PersistError::NotFound { .. } => Errors::ValidationError {
+                message: format!(
+                    "No ticket stored for branch '{branch_name}', add one via 'git-kit context'"
+                ),
+            },
+            e => Errors::PersistError(e),
+        })?;
+
+    let merge_base = git.merge_base(base).map_err(Errors::Git)?;
+    let commits = git
+        .log(&format!("{merge_base}..HEAD"), None)
+        .map_err(Errors::Git)?;
+
+    let mut changes = vec![];
+    for commit in commits {
+        let Some(parsed) = parser.parse(&commit.message) else {
+            log::info!("skipping commit '{}' not matching a template", commit.hash);
+            continue;
+        };
+
+        let template = config.templates[&parsed.template].clone();
+        if parsed.ticket.is_some() || !template.contains_placeholder("ticket_num") {
+            continue;
+        }
+
+        let inputs = Commit {
+            template_name: parsed.template,
+            template,
+            ticket: Some(branch.ticket.clone()),
+            message: parsed.message,
+            scope: parsed.scope,
+            body: parsed.body,
+            footers: vec![],
+            ticket_link: config.ticket_link.clone(),
+            ticket_separator: config.ticket_separator.clone(),
+        };
+
+        let after = reword::render(&inputs, parsed.link.or_else(|| branch.link.clone()))?;
+
+        if after.trim() != commit.message.trim() {
+            changes.push(TicketChange {
+                hash: commit.hash,
+                before: commit.message,
+                after,
+            });
+        }
+    }
+
+    if let Some(oldest) = changes.last() {
+        reword::ensure_rewritable(git, &oldest.hash)?;
+    }
+
+    if !dry_run && !changes.is_empty() {
+        let messages = changes
+            .iter()
+            .map(|c| (c.hash.clone(), c.after.clone()))
+            .collect::<Vec<_>>();
+
+        git.reword(&messages).map_err(Errors::Git)?;
+    }
+
+    Ok(changes)
+}
@@ -0,0 +1,159 @@
+use crate::domain::{
+    adapters::{Git, Store},
+    errors::Errors,
+    models::Branch,
+};
+
+/// Stored branch context whose branch no longer exists locally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Orphan {
+    /// Name of the branch the context was stored against.
+    pub name: String,
+    pub branch: Branch,
+    /// Existing branch the orphaned branch was renamed to according to the reflog.
+    pub renamed: Option<String>,
+}
+
+/// Rename a git branch moving any stored context along with it.
+pub fn rename<G: Git, S: Store>(
+    git: &G,
+    store: &mut S,
+    from: &str,
+    to: &str,
+) -> Result<Option<Branch>, Errors> {
+    let (from, to) = (from.trim(), to.trim());
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+
+    if store.get_branch(to, &repo_name).is_ok() {
+        return Err(Errors::ValidationError {
+            message: format!("Context is already stored for branch '{to}'"),
+        });
+    }
+
+    let has_context = store.get_branch(from, &repo_name).is_ok();
+
+    git.rename_branch(from, to).map_err(Errors::Git)?;
+
+    if !has_context {
+        return Ok(None);
+    }
+
+    match store.rename_branch(from, to, &repo_name) {
+        Ok(branch) => Ok(Some(branch)),
+        Err(e) => {
+            // Restore the original branch name so git & the stored context stay in step.
+            if let Err(e) = git.rename_branch(to, from) {
+                log::error!("Failed to restore branch name '{}': {}", from, e);
+            }
+
+            Err(Errors::PersistError(e))
+        }
+    }
+}
+
+/// Stored contexts for branches which no longer exist, along with any rename found in the reflog.
+pub fn orphans<G: Git, S: Store>(git: &G, store: &S) -> Result<Vec<Orphan>, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let local = git.branches().map_err(Errors::Git)?;
+    let renames = git.branch_renames().map_err(Errors::Git)?;
+
+    // Finished branches are expected to be deleted once archived.
+    let stored: Vec<Branch> = store
+        .get_branches(&repo_name)
+        .map_err(Errors::PersistError)?
+        .into_iter()
+        .filter(|branch| branch.archived.is_none())
+        .collect();
+
+    let prefix = format!("{}-", repo_name.trim());
+    let names: Vec<String> = stored
+        .iter()
+        .map(|branch| {
+            let name = branch.name.strip_prefix(&prefix).unwrap_or(&branch.name);
+            name.to_string()
+        })
+        .collect();
+
+    let orphans = stored
+        .into_iter()
+        .zip(names.iter())
+        .filter(|(_, name)| !local.contains(name))
+        .map(|(branch, name)| Orphan {
+            name: name.clone(),
+            renamed: renamed_to(name, &renames, &local).filter(|to| !names.contains(to)),
+            branch,
+        })
+        // Contexts stored without a repo can't be told apart from a sibling repo's (i.e. `api` &
+        // `api-gateway`) by name alone, so are only offered once this repo's reflog renamed them.
+        .filter(|orphan| orphan.branch.repo.is_some() || orphan.renamed.is_some())
+        .collect();
+
+    Ok(orphans)
+}
+
+/// Move an orphaned branch context onto an existing branch.
+pub fn attach<G: Git, S: Store>(
+    git: &G,
+    store: &mut S,
+    from: &str,
+    to: &str,
+) -> Result<Branch, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+
+    store
+        .rename_branch(from, to, &repo_name)
+        .map_err(Errors::PersistError)
+}
+
+/// Follow a chain of renames (i.e. `a -> b -> c`) to a branch that still exists.
+fn renamed_to(name: &str, renames: &[(String, String)], local: &[String]) -> Option<String> {
+    let mut current = name;
+
+    // Bounded to avoid looping on a branch renamed back & forth.
+    for _ in 0..renames.len() {
+        let (_, to) = renames.iter().find(|(from, _)| from == current)?;
+        current = to;
+
+        if local.iter().any(|branch| branch == current) {
+            return Some(current.into());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn renames(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn renamed_to_follows_chain_of_renames() {
+        let renames = renames(&[("b", "c"), ("a", "b")]);
+        let local = vec!["main".to_string(), "c".to_string()];
+
+        assert_eq!(renamed_to("a", &renames, &local), Some("c".into()));
+    }
+
+    #[test]
+    fn renamed_to_none_when_renamed_branch_deleted() {
+        let renames = renames(&[("a", "b")]);
+        let local = vec!["main".to_string()];
+
+        assert_eq!(renamed_to("a", &renames, &local), None);
+    }
+
+    #[test]
+    fn renamed_to_stops_on_rename_cycle() {
+        let renames = renames(&[("a", "b"), ("b", "a")]);
+        let local = vec!["main".to_string()];
+
+        assert_eq!(renamed_to("a", &renames, &local), None);
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    domain::{
+        adapters::{Git, Store},
+        errors::Errors,
+        models::GitCommit,
+        parser::{CommitParser, ParsedCommit},
+    },
+    template_config::CommitConfig,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Changelog {
+    pub range: String,
+    pub sections: Vec<Section>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Section {
+    pub title: String,
+    /// Entries without a scope are listed first followed by each scope alphabetically.
+    pub scopes: Vec<ScopeEntries>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScopeEntries {
+    pub scope: Option<String>,
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Entry {
+    pub hash: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    pub template: Option<String>,
+    pub scope: Option<String>,
+    pub message: String,
+    pub ticket: Option<String>,
+    pub link: Option<String>,
+}
+
+impl Entry {
+    pub(crate) fn new(
+        commit: GitCommit,
+        parsed: Option<ParsedCommit>,
+        links: &HashMap<String, String>,
+    ) -> Entry {
+        let parsed = parsed.unwrap_or_default();
+        let link = parsed
+            .link
+            .or_else(|| parsed.ticket.as_ref().and_then(|t| links.get(t).cloned()));
+
+        Entry {
+            message: parsed
+                .message
+                .unwrap_or_else(|| commit.subject().to_string()),
+            template: Some(parsed.template).filter(|t| !t.is_empty()),
+            scope: parsed.scope,
+            ticket: parsed.ticket,
+            link,
+            hash: commit.hash,
+            author: commit.author,
+            date: commit.date,
+        }
+    }
+}
+
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    config: &CommitConfig,
+    range: &str,
+) -> Result<Changelog, Errors> {
+    let parser = CommitParser::new(&config.templates).map_err(|e| Errors::Configuration {
+        message: "Failed to parse commit templates".into(),
+        source: e,
+    })?;
+
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let links = ticket_links(store, &repo_name);
+
+    let mut sections: Vec<(String, Vec<Entry>)> = section_titles(config)
+        .into_iter()
+        .map(|title| (title, vec![]))
+        .collect();
+
+    for commit in git.log(range, None).map_err(Errors::Git)? {
+        let parsed = parser.parse(&commit.message);
+
+        let title = match &parsed {
+            Some(parsed) => section_title(config, &parsed.template),
+            None => config.changelog.other.clone(),
+        };
+
+        let Some(title) = title else {
+            log::info!("skipping commit '{}' without a section", commit.hash);
+            continue;
+        };
+
+        let entry = Entry::new(commit, parsed, &links);
+
+        match sections.iter_mut().find(|(t, _)| t == &title) {
+            Some((_, entries)) => entries.push(entry),
+            None => sections.push((title, vec![entry])),
+        }
+    }
+
+    let sections = sections
+        .into_iter()
+        .filter(|(_, entries)| !entries.is_empty())
+        .map(|(title, entries)| Section {
+            title,
+            scopes: group_by_scope(entries),
+        })
+        .collect();
+
+    Ok(Changelog {
+        range: range.into(),
+        sections,
+    })
+}
+
+/// Links stored against branch tickets within the repository, most recent branch first.
+pub fn ticket_links<S: Store>(store: &S, repo_name: &str) -> HashMap<String, String> {
+    let branches = store.get_branches(repo_name).unwrap_or_else(|e| {
+        log::error!("Failed to retrieve branch links: {}", e);
+        vec![]
+    });
+
+    let mut links = HashMap::new();
+    for ticket in branches.iter().flat_map(|b| b.all_tickets()) {
+        if let Some(link) = ticket.link {
+            links.entry(ticket.ticket).or_insert(link);
+        }
+    }
+
+    links
+}
+
+/// Configured section titles in order, otherwise each template in alphabetical order.
+fn section_titles(config: &CommitConfig) -> Vec<String> {
+    let titles: Vec<String> = match config.changelog.sections.is_empty() {
+        true => {
+            let mut names = config.templates.keys().cloned().collect::<Vec<_>>();
+            names.sort();
+            names
+        }
+        false => config
+            .changelog
+            .sections
+            .iter()
+            .map(|s| s.title.clone())
+            .collect(),
+    };
+
+    let mut unique: Vec<String> = vec![];
+    for title in titles {
+        if !unique.contains(&title) {
+            unique.push(title);
+        }
+    }
+
+    unique
+}
+
+fn section_title(config: &CommitConfig, template: &str) -> Option<String> {
+    if config.changelog.sections.is_empty() {
+        return Some(template.into());
+    }
+
+    config
+        .changelog
+        .sections
+        .iter()
+        .find(|s| s.template == template)
+        .map(|s| s.title.clone())
+        .or_else(|| config.changelog.other.clone())
+}
+
+fn group_by_scope(entries: Vec<Entry>) -> Vec<ScopeEntries> {
+    let mut scopes: Vec<ScopeEntries> = vec![];
+
+    for entry in entries {
+        match scopes.iter_mut().find(|s| s.scope == entry.scope) {
+            Some(group) => group.entries.push(entry),
+            None => scopes.push(ScopeEntries {
+                scope: entry.scope.clone(),
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    // 'None' is ordered before any scope.
+    scopes.sort_by(|a, b| a.scope.cmp(&b.scope));
+    scopes
+}
+
+impl Changelog {
+    pub fn to_markdown(&self) -> String {
+        let mut lines = vec![];
+
+        for section in &self.sections {
+            lines.push(format!("## {}", section.title));
+            lines.push("".into());
+
+            for group in &section.scopes {
+                for entry in &group.entries {
+                    let scope = group
+                        .scope
+                        .as_ref()
+                        .map(|s| format!("**{s}:** "))
+                        .unwrap_or_default();
+
+                    let ticket = match (&entry.ticket, &entry.link) {
+                        (Some(ticket), Some(link)) => format!(" ([{ticket}]({link}))"),
+                        (Some(ticket), None) => format!(" ({ticket})"),
+                        _ => "".into(),
+                    };
+
+                    lines.push(format!(
+                        "- {scope}{}{ticket} ({})",
+                        entry.message,
+                        &entry.hash[..entry.hash.len().min(7)]
+                    ));
+                }
+            }
+
+            lines.push("".into());
+        }
+
+        lines.join("\n").trim_end().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(message: &str, ticket: Option<&str>, link: Option<&str>) -> Entry {
+        Entry {
+            hash: "abc1234def".into(),
+            author: "author".into(),
+            date: Utc::now(),
+            template: None,
+            scope: None,
+            message: message.into(),
+            ticket: ticket.map(String::from),
+            link: link.map(String::from),
+        }
+    }
+
+    #[test]
+    fn to_markdown_renders_sections_scopes_and_ticket_links() {
+        let changelog = Changelog {
+            range: "HEAD".into(),
+            sections: vec![
+                Section {
+                    title: "Features".into(),
+                    scopes: vec![
+                        ScopeEntries {
+                            scope: None,
+                            entries: vec![entry("Add", Some("ABC-1"), Some("https://abc/1"))],
+                        },
+                        ScopeEntries {
+                            scope: Some("cli".into()),
+                            entries: vec![entry("Flag", Some("ABC-2"), None)],
+                        },
+                    ],
+                },
+                Section {
+                    title: "Fixes".into(),
+                    scopes: vec![ScopeEntries {
+                        scope: None,
+                        entries: vec![entry("Fix", None, None)],
+                    }],
+                },
+            ],
+        };
+
+        let expected = [
+            "## Features",
+            "",
+            "- Add ([ABC-1](https://abc/1)) (abc1234)",
+            "- **cli:** Flag (ABC-2) (abc1234)",
+            "",
+            "## Fixes",
+            "",
+            "- Fix (abc1234)",
+        ]
+        .join("\n");
+
+        assert_eq!(expected, changelog.to_markdown());
+    }
+
+    #[test]
+    fn group_by_scope_orders_unscoped_first() {
+        let entries = [Some("b"), None, Some("a"), Some("b")]
+            .into_iter()
+            .map(|scope| Entry {
+                scope: scope.map(String::from),
+                ..entry("message", None, None)
+            })
+            .collect();
+
+        let scopes = group_by_scope(entries)
+            .into_iter()
+            .map(|s| (s.scope, s.entries.len()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            scopes,
+            [(None, 1), (Some("a".into()), 1), (Some("b".into()), 2)]
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use chrono::Utc;
+
 use crate::domain::{
-    adapters::{CheckoutStatus, Git, Store},
+    adapters::{BranchLocation, CheckoutStatus, Git, Store},
+    commands::context::Context,
     errors::Errors,
     models::Branch,
 };
@@ -8,34 +11,133 @@ use crate::domain::{
 pub struct Checkout {
     /// Name of the branch to checkout or create.
     pub name: String,
-    /// Issue ticket number related to the branch.
-    pub ticket: Option<String>,
+    /// Issue ticket numbers related to the branch, the first being the primary ticket.
+    pub tickets: Vec<String>,
     /// Short describing a section of the codebase the changes relate to.
     pub scope: Option<String>,
-    /// Issue ticket number link.
-    pub link: Option<String>,
+    /// Issue ticket links matched to tickets by position.
+    pub links: Vec<String>,
+    /// Context inherited by a newly created branch for any values not provided.
+    pub parent: Option<Context>,
+    /// Start point for a new branch, defaults to HEAD.
+    pub from: Option<String>,
+    /// Create the branch tracking the remote branch of the same name.
+    pub track: bool,
 }
 
-pub fn handler<G: Git, S: Store>(git: &G, store: &S, args: Checkout) -> Result<Branch, Errors> {
-    // Attempt to create branch
-    let create = git.checkout(&args.name, CheckoutStatus::New);
+/// Checkout the branch with a status resolved via [`status`] & store its context.
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    mut args: Checkout,
+    status: CheckoutStatus,
+) -> Result<Branch, Errors> {
+    let is_new = matches!(status, CheckoutStatus::New { .. });
 
-    // If the branch already exists check it out
-    if let Err(err) = create {
-        log::error!("failed to create new branch: {}", err);
+    git.checkout(&args.name, status).map_err(Errors::Git)?;
 
-        git.checkout(&args.name, CheckoutStatus::Existing)
-            .map_err(Errors::Git)?;
+    if let Some(parent) = args.parent.take().filter(|_| is_new) {
+        inherit(&mut args, parent);
     }
 
     // We want to store the branch name against and ticket number
     // So whenever we commit we get the ticket number from the branch
     let repo_name = git.repository_name().map_err(Errors::Git)?;
 
-    let branch = Branch::new(&args.name, &repo_name, args.ticket, args.link, args.scope);
+    let mut branch =
+        Branch::with_tickets(&args.name, &repo_name, args.tickets, args.links, args.scope);
+    branch.last_used = Some(Utc::now());
+    // Keep any key/value context already set against an existing branch.
+    if let Ok(existing) = store.get_branch(&args.name, &repo_name) {
+        branch.data = existing.data;
+    }
+
     store
         .persist_branch(&branch)
         .map_err(Errors::PersistError)?;
 
     Ok(branch)
 }
+
+/// Checkout an existing local branch, otherwise create one from a start point or the remote branch.
+pub fn status<G: Git>(
+    git: &G,
+    name: &str,
+    from: Option<&str>,
+    track: bool,
+) -> Result<CheckoutStatus, Errors> {
+    let location = git.branch_location(name).map_err(Errors::Git)?;
+
+    let status = match (location, from, track) {
+        (Some(BranchLocation::Local), None, false) => CheckoutStatus::Existing,
+        (Some(BranchLocation::Local), ..) => {
+            return Err(Errors::ValidationError {
+                message: format!(
+                    "Branch '{}' already exists, '--from' & '--track' only apply when creating a branch",
+                    name
+                ),
+            })
+        }
+        (Some(BranchLocation::Remote { upstream }), None, _) => CheckoutStatus::Track { upstream },
+        (_, Some(_), true) => {
+            return Err(Errors::ValidationError {
+                message: "A tracking branch starts from the remote branch so can't also use '--from'"
+                    .into(),
+            })
+        }
+        (None, _, true) => {
+            return Err(Errors::ValidationError {
+                message: format!("No remote branch '{}' found to track", name),
+            })
+        }
+        (_, from, false) => CheckoutStatus::New {
+            from: from.map(String::from),
+        },
+    };
+
+    log::info!("checkout '{}' with status {:?}", name, status);
+
+    Ok(status)
+}
+
+/// Ticket, scope & links stored against the currently checked out branch, read before switching branches.
+pub fn parent_context<G: Git, S: Store>(git: &G, store: &S) -> Option<Context> {
+    let repo_name = git.repository_name().ok()?;
+    let branch_name = git.branch_name().ok()?;
+    let branch = store.get_branch(&branch_name, &repo_name).ok()?;
+
+    // Branches without a ticket default to the branch name which isn't worth inheriting.
+    let (tickets, links) = match branch.ticket.trim() == branch_name.trim() {
+        true => (vec![], vec![]),
+        false => branch
+            .all_tickets()
+            .into_iter()
+            .map(|t| (t.ticket, t.link.unwrap_or_default()))
+            .unzip(),
+    };
+
+    let context = Context {
+        tickets,
+        scope: branch.scope,
+        links,
+    };
+
+    match context.tickets.is_empty() && context.scope.is_none() {
+        true => None,
+        false => Some(context),
+    }
+}
+
+fn inherit(args: &mut Checkout, parent: Context) {
+    log::info!("inheriting context for new branch '{}'", args.name);
+
+    // Links belong to their tickets so are only inherited along with them.
+    if args.tickets.is_empty() {
+        args.tickets = parent.tickets;
+        args.links = parent.links;
+    }
+
+    if args.scope.is_none() {
+        args.scope = parent.scope;
+    }
+}
@@ -1,46 +1,144 @@
+use std::collections::BTreeMap;
+
 use crate::{
-    domain::{models::Branch, template::Templator},
+    domain::{
+        models::{render_ticket_link, Branch, CommitInputs},
+        template::Templator,
+    },
     template_config::Template,
     utils::string::OptionStr,
 };
 
 #[derive(Debug, Clone)]
 pub struct Commit {
+    /// Name of the template within the template config.
+    pub template_name: String,
     pub template: Template,
     pub ticket: Option<String>,
     pub message: Option<String>,
     pub scope: Option<String>,
+    /// Multi-line commit body injected into `{body}`.
+    pub body: Option<String>,
+    /// Ordered footer token & value pairs injected into `{footers}`.
+    pub footers: Vec<(String, String)>,
+    /// Issue tracker url pattern used to render `{link}` when the branch has no stored link.
+    pub ticket_link: Option<String>,
+    /// Separator joining every branch ticket into `{tickets}`, defaults to `, `.
+    pub ticket_separator: Option<String>,
 }
 
 impl Commit {
+    /// Inputs used to render the commit message.
+    pub fn inputs(&self) -> CommitInputs {
+        CommitInputs {
+            ticket: self.ticket.clone(),
+            scope: self.scope.clone(),
+            message: self.message.clone(),
+            body: self.body.clone(),
+            footers: self.footers.clone(),
+        }
+    }
+
     pub fn commit_message(
         &self,
         template: String,
         branch: Option<Branch>,
     ) -> anyhow::Result<String> {
         log::info!("generate commit message for '{}'", &template);
-        let (ticket, scope, link) = branch
-            .map(|branch| (Some(branch.ticket), branch.scope, branch.link))
-            .unwrap_or((None, None, None));
 
-        let ticket = self
-            .ticket
-            .clone()
-            .none_if_empty()
-            .or_else(|| ticket.none_if_empty());
+        // Rendered in a single pass so user input containing braces isn't treated as placeholders.
+        template.render(&self.values(branch))
+    }
 
-        let scope = self.scope.clone().none_if_empty().or(scope.none_if_empty());
+    /// Placeholder values from the inputs, falling back to the branch context.
+    pub(crate) fn values(&self, branch: Option<Branch>) -> BTreeMap<String, Option<String>> {
+        let footers = self
+            .footers
+            .iter()
+            .map(|(token, value)| format!("{token}: {value}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut values = branch_values(
+            branch,
+            self.ticket.clone(),
+            self.scope.clone(),
+            self.ticket_link.as_deref(),
+            self.ticket_separator.as_deref(),
+        );
 
-        let contents = template
-            .replace_or_remove("ticket_num", ticket)?
-            .replace_or_remove("scope", scope)?
-            .replace_or_remove("link", link)?
-            .replace_or_remove("message", self.message.clone())?;
+        values.extend([
+            ("message".into(), self.message.clone()),
+            ("body".into(), self.body.clone()),
+            ("footers".into(), footers.none_if_empty()),
+        ]);
 
-        Ok(contents)
+        values
     }
 }
 
+/// Values for the `{ticket_num}`, `{tickets}`, `{scope}` & `{link}` placeholders along with any key/value
+/// context set against the branch, a provided ticket or scope takes precedence over the branch's.
+pub fn branch_values(
+    branch: Option<Branch>,
+    ticket: Option<String>,
+    scope: Option<String>,
+    ticket_link: Option<&str>,
+    ticket_separator: Option<&str>,
+) -> BTreeMap<String, Option<String>> {
+    let (branch_ticket, branch_scope, link, others, data) = match branch {
+        Some(branch) => (
+            Some(branch.ticket),
+            branch.scope,
+            branch.link,
+            branch.tickets,
+            branch.data,
+        ),
+        None => (None, None, None, vec![], BTreeMap::new()),
+    };
+
+    let ticket = ticket
+        .none_if_empty()
+        .or_else(|| branch_ticket.none_if_empty());
+
+    // Primary ticket followed by any additional branch tickets.
+    let tickets = ticket
+        .iter()
+        .cloned()
+        .chain(
+            others
+                .into_iter()
+                .map(|t| t.ticket)
+                .filter(|t| Some(t) != ticket.as_ref()),
+        )
+        .collect::<Vec<_>>()
+        .join(ticket_separator.unwrap_or(", "));
+
+    let scope = scope.none_if_empty().or(branch_scope.none_if_empty());
+
+    let link = link.none_if_empty().or_else(|| {
+        let pattern = ticket_link?;
+        ticket
+            .as_ref()
+            .map(|ticket| render_ticket_link(pattern, ticket))
+    });
+
+    // Key/value context set against the branch, built in placeholders take precedence.
+    let mut values = data
+        .into_iter()
+        .map(|(key, value)| (key, Some(value)))
+        .collect::<BTreeMap<_, _>>();
+
+    values.extend([
+        ("ticket_num".into(), ticket),
+        ("tickets".into(), tickets.none_if_empty()),
+        ("scope".into(), scope),
+        ("link".into(), link),
+    ]);
+
+    values
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{Fake, Faker};
@@ -72,7 +170,7 @@ mod tests {
         };
 
         let actual = args.commit_message("{ticket_num} {message}".into(), None)?;
-        let expected = format!("{}", args.message.unwrap());
+        let expected = args.message.unwrap().to_string();
 
         assert_eq!(actual, expected);
 
@@ -106,7 +204,7 @@ mod tests {
             };
 
             let actual = args.commit_message("[{ticket_num}] {message}".into(), None)?;
-            let expected = format!("{}", args.message.unwrap());
+            let expected = args.message.unwrap().to_string();
 
             assert_eq!(actual, expected);
         }
@@ -119,6 +217,7 @@ mod tests {
         let template = Template {
             description: Faker.fake(),
             content: "[{ticket_num}] {message}".into(),
+            footers: vec![],
         };
 
         let args = Commit {
@@ -128,7 +227,7 @@ mod tests {
             ..fake_args()
         };
 
-        let actual = args.commit_message(template.content.into(), None)?;
+        let actual = args.commit_message(template.content, None)?;
         let expected = format!("[{}] {}", args.ticket.unwrap(), args.message.unwrap());
 
         assert_eq!(actual, expected);
@@ -145,7 +244,7 @@ mod tests {
         };
 
         let actual = args.commit_message("{ticket_num} {message}".into(), None)?;
-        let expected = format!("{}", args.ticket.unwrap());
+        let expected = args.ticket.unwrap().to_string();
 
         assert_eq!(expected.trim(), actual);
 
@@ -195,15 +294,190 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn commit_template_body_and_footers_are_injected() -> anyhow::Result<()> {
+        let args = Commit {
+            message: Some(Faker.fake()),
+            body: Some("line one\nline two".into()),
+            footers: vec![
+                ("BREAKING CHANGE".into(), "removed flag".into()),
+                ("Co-authored-by".into(), "someone".into()),
+            ],
+            ..fake_args()
+        };
+
+        let actual = args.commit_message("{message}\n\n{body}\n\n{footers}".into(), None)?;
+        let expected = format!(
+            "{}\n\nline one\nline two\n\nBREAKING CHANGE: removed flag\nCo-authored-by: someone",
+            args.message.unwrap()
+        );
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_without_body_or_footers_removes_placeholders() -> anyhow::Result<()> {
+        let args = Commit {
+            message: Some(Faker.fake()),
+            body: None,
+            footers: vec![],
+            ..fake_args()
+        };
+
+        let actual = args.commit_message("{message}\n\n{body}\n\n{footers}".into(), None)?;
+
+        assert_eq!(args.message.unwrap(), actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_link_is_rendered_from_ticket_link_pattern() -> anyhow::Result<()> {
+        let args = Commit {
+            ticket: Some("ABC-1".into()),
+            message: Some("Fix".into()),
+            ticket_link: Some("https://tracker/browse/{ticket_num}".into()),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message("{message}\n\nIssue: {link}".into(), None)?;
+
+        assert_eq!("Fix\n\nIssue: https://tracker/browse/ABC-1", actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_link_prefers_stored_branch_link() -> anyhow::Result<()> {
+        let branch = Branch::new(
+            "name",
+            "repo",
+            Some("ABC-1".into()),
+            Some("https://stored/ABC-1".into()),
+            None,
+        );
+
+        let args = Commit {
+            ticket: None,
+            ticket_link: Some("https://tracker/browse/{ticket_num}".into()),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message("{link}".into(), Some(branch))?;
+
+        assert_eq!("https://stored/ABC-1", actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_tickets_joins_all_branch_tickets() -> anyhow::Result<()> {
+        let branch = Branch::with_tickets(
+            "name",
+            "repo",
+            vec!["ABC-1".into(), "ABC-2".into(), "ABC-3".into()],
+            vec![],
+            None,
+        );
+
+        for (separator, expected) in [
+            (None, "[ABC-1, ABC-2, ABC-3] Fix"),
+            (Some(" | ".to_string()), "[ABC-1 | ABC-2 | ABC-3] Fix"),
+        ] {
+            let args = Commit {
+                ticket: None,
+                message: Some("Fix".into()),
+                ticket_separator: separator,
+                ..fake_args()
+            };
+
+            let actual =
+                args.commit_message("[{tickets}] {message}".into(), Some(branch.clone()))?;
+
+            assert_eq!(expected, actual);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_tickets_removed_without_any_ticket() -> anyhow::Result<()> {
+        let args = Commit {
+            ticket: None,
+            message: Some("Fix".into()),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message("[{tickets}] {message}".into(), None)?;
+
+        assert_eq!("Fix", actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_context_keys_are_injected() -> anyhow::Result<()> {
+        let mut branch = Branch::new("name", "repo", Some("ABC-1".into()), None, None);
+        branch.data.insert("reviewer".into(), "alice".into());
+
+        let args = Commit {
+            ticket: None,
+            message: Some("Fix".into()),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message(
+            "[{ticket_num}] {message}\n\nReviewer: {reviewer}\nPairs: ({pair})".into(),
+            Some(branch),
+        )?;
+
+        assert_eq!("[ABC-1] Fix\n\nReviewer: alice\nPairs:", actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_keeps_braces_within_user_input() -> anyhow::Result<()> {
+        let mut branch = Branch::new("name", "repo", Some("ABC-1".into()), None, None);
+        branch.data.insert("reviewer".into(), "alice".into());
+
+        let args = Commit {
+            ticket: None,
+            message: Some("Escape {reviewer} in {config}".into()),
+            body: Some("Uses {message} & {}".into()),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message(
+            "[{ticket_num}] {message}\n\n{body}\n\nReviewer: {reviewer}".into(),
+            Some(branch),
+        )?;
+
+        assert_eq!(
+            "[ABC-1] Escape {reviewer} in {config}\n\nUses {message} & {}\n\nReviewer: alice",
+            actual
+        );
+
+        Ok(())
+    }
+
     fn fake_args() -> Commit {
         Commit {
+            template_name: Faker.fake(),
             template: Template {
                 description: Faker.fake(),
                 content: Faker.fake(),
+                footers: vec![],
             },
             ticket: Faker.fake(),
             message: Faker.fake(),
             scope: Faker.fake(),
+            body: Faker.fake(),
+            footers: vec![],
+            ticket_link: None,
+            ticket_separator: None,
         }
     }
 }
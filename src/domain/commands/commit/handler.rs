@@ -1,13 +1,36 @@
 use crate::{
     domain::{
         adapters::{CommitMsgStatus, Git, Store},
-        errors::Errors,
+        errors::{Errors, GitError},
+        models::{CommitHistory, CommitStatus},
     },
     utils::string::OptionStr,
 };
 
 use super::Commit;
 
+/// Validate the repository is in a state to commit before any prompts are shown.
+/// Tracked changes are staged after these checks, staging mid merge would mark conflicts as resolved.
+pub fn preflight<G: Git>(git: &G, stage_all: bool) -> Result<(), Errors> {
+    if let Some(operation) = git.operation_in_progress().map_err(Errors::Git)? {
+        return Err(Errors::Git(GitError::InProgress { operation }));
+    }
+
+    if git.is_detached().map_err(Errors::Git)? {
+        return Err(Errors::Git(GitError::DetachedHead));
+    }
+
+    if stage_all {
+        git.stage_tracked().map_err(Errors::Git)?;
+    }
+
+    if git.staged_files().map_err(Errors::Git)?.is_empty() {
+        return Err(Errors::Git(GitError::NothingStaged));
+    }
+
+    Ok(())
+}
+
 pub fn handler<G: Git, S: Store>(git: &G, store: &S, commit: Commit) -> Result<String, Errors> {
     let branch_name = git.branch_name().map_err(Errors::Git)?;
     let repo_name = git.repository_name().map_err(Errors::Git)?;
@@ -21,21 +44,71 @@ pub fn handler<G: Git, S: Store>(git: &G, store: &S, commit: Commit) -> Result<S
             source: e,
         })?;
 
-    let template_file = git.template_file_path().map_err(Errors::Git)?;
-
-    std::fs::write(&template_file, &contents).map_err(|_| Errors::ValidationError {
-        message: "Failed attempting to write commit template file".into(),
-    })?;
-
     // Pre-cautionary measure encase 'message' is provided but still matches template exactly.
     // Otherwise git will just abort the commit if theres no difference / change from the template.
-    let commit_msg_complete = match commit.message.none_if_empty() {
+    let commit_msg_complete = match commit.message.clone().none_if_empty() {
         Some(_) => CommitMsgStatus::Completed,
         None => CommitMsgStatus::InComplete,
     };
 
-    git.commit_with_template(&template_file, commit_msg_complete)
-        .map_err(Errors::Git)?;
+    let history = CommitHistory::new(
+        &repo_name,
+        &branch_name,
+        &commit.template_name,
+        commit.inputs(),
+        &contents,
+    );
+
+    commit_with_history(git, store, history, commit_msg_complete)?;
 
     Ok(contents)
 }
+
+/// Commit using a previously rendered message.
+pub fn retry<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    history: CommitHistory,
+) -> Result<String, Errors> {
+    let contents = history.message.clone();
+
+    commit_with_history(git, store, history, CommitMsgStatus::Completed)?;
+
+    Ok(contents)
+}
+
+/// Persist the rendered message before committing so it can be recovered if the commit fails or is aborted.
+fn commit_with_history<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    mut history: CommitHistory,
+    completed: CommitMsgStatus,
+) -> Result<(), Errors> {
+    let template_file = git.template_file_path().map_err(Errors::Git)?;
+
+    std::fs::write(&template_file, &history.message).map_err(|_| Errors::ValidationError {
+        message: "Failed attempting to write commit template file".into(),
+    })?;
+
+    persist_history(store, &history);
+
+    let result = git
+        .commit_with_template(&template_file, completed)
+        .map_err(Errors::Git);
+
+    history.status = match result {
+        Ok(_) => CommitStatus::Committed,
+        Err(_) => CommitStatus::Failed,
+    };
+
+    persist_history(store, &history);
+
+    result
+}
+
+/// History is best effort and should never prevent a commit.
+fn persist_history<S: Store>(store: &S, history: &CommitHistory) {
+    if let Err(e) = store.persist_history(history) {
+        log::error!("Failed to persist commit history '{}': {}", history.id, e);
+    }
+}
@@ -1,5 +1,5 @@
 mod args;
 mod handler;
 
-pub use args::Commit;
-pub use handler::handler;
+pub use args::{branch_values, Commit};
+pub use handler::{handler, preflight, retry};
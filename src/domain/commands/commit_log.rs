@@ -0,0 +1,67 @@
+use crate::{
+    domain::{
+        adapters::{Git, Store},
+        commands::changelog::{ticket_links, Entry},
+        errors::Errors,
+        parser::CommitParser,
+    },
+    template_config::CommitConfig,
+};
+
+/// Filters applied to commits once parsed, `None` matches everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Template name i.e. `feat`.
+    pub template: Option<String>,
+    pub scope: Option<String>,
+    pub ticket: Option<String>,
+}
+
+impl LogFilter {
+    fn matches(&self, entry: &Entry) -> bool {
+        let matches = |filter: &Option<String>, value: &Option<String>| match (filter, value) {
+            (None, _) => true,
+            (Some(filter), Some(value)) => filter.eq_ignore_ascii_case(value),
+            (Some(_), None) => false,
+        };
+
+        matches(&self.template, &entry.template)
+            && matches(&self.scope, &entry.scope)
+            && matches(&self.ticket, &entry.ticket)
+    }
+}
+
+/// Recent commits within a revision range decorated with parsed template values.
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    config: &CommitConfig,
+    range: &str,
+    filter: &LogFilter,
+    limit: usize,
+) -> Result<Vec<Entry>, Errors> {
+    let parser = CommitParser::new(&config.templates).map_err(|e| Errors::Configuration {
+        message: "Failed to parse commit templates".into(),
+        source: e,
+    })?;
+
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let links = ticket_links(store, &repo_name);
+
+    // Filters apply to parsed commits, so only a plain log can be limited by git.
+    let max_count = (*filter == LogFilter::default()).then_some(limit);
+
+    let entries = git
+        .log(range, max_count)
+        .map_err(Errors::Git)?
+        .into_iter()
+        .map(|commit| {
+            let parsed = parser.parse(&commit.message);
+            Entry::new(commit, parsed, &links)
+        })
+        .filter(|entry| filter.matches(entry))
+        .take(limit)
+        .collect();
+
+    Ok(entries)
+}
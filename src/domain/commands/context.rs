@@ -4,14 +4,25 @@ use crate::domain::{
     models::Branch,
 };
 
+/// Placeholders already populated by commit inputs which can't be overridden by context values.
+const RESERVED_KEYS: [&str; 7] = [
+    "ticket_num",
+    "tickets",
+    "scope",
+    "link",
+    "message",
+    "body",
+    "footers",
+];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Context {
-    /// Issue ticket number related to the current branch.
-    pub ticket: Option<String>,
+    /// Issue ticket numbers related to the current branch, the first being the primary ticket.
+    pub tickets: Vec<String>,
     /// Short describing a section of the codebase the changes relate to.
     pub scope: Option<String>,
-    /// Issue ticket number link.
-    pub link: Option<String>,
+    /// Issue ticket links matched to tickets by position.
+    pub links: Vec<String>,
 }
 
 pub fn handler<G: Git, S: Store>(git: &G, store: &S, args: Context) -> Result<Branch, Errors> {
@@ -21,10 +32,90 @@ pub fn handler<G: Git, S: Store>(git: &G, store: &S, args: Context) -> Result<Br
 
     let branch_name = git.branch_name().map_err(Errors::Git)?;
 
-    let branch = Branch::new(&branch_name, &repo_name, args.ticket, args.link, args.scope);
+    let mut branch = Branch::with_tickets(
+        &branch_name,
+        &repo_name,
+        args.tickets,
+        args.links,
+        args.scope,
+    );
+
+    // Keep any key/value context & usage already set against the branch.
+    if let Ok(existing) = store.get_branch(&branch_name, &repo_name) {
+        branch.data = existing.data;
+        branch.last_used = existing.last_used;
+    }
+
     store
         .persist_branch(&branch)
         .map_err(Errors::PersistError)?;
 
     Ok(branch)
 }
+
+/// Set a key/value available as a template placeholder for the current branch.
+pub fn set<G: Git, S: Store>(git: &G, store: &S, key: &str, value: &str) -> Result<Branch, Errors> {
+    let key = key.trim();
+
+    if key.is_empty() || !key.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(Errors::ValidationError {
+            message: format!(
+                "Context key '{key}' may only contain letters, numbers and underscores"
+            ),
+        });
+    }
+
+    if RESERVED_KEYS.contains(&key) {
+        return Err(Errors::ValidationError {
+            message: format!("Context key '{key}' is reserved for commit inputs"),
+        });
+    }
+
+    let mut branch = current_or_new(git, store)?;
+    branch.data.insert(key.into(), value.trim().into());
+
+    store
+        .persist_branch(&branch)
+        .map_err(Errors::PersistError)?;
+
+    Ok(branch)
+}
+
+/// Remove a key/value from the current branch, returning whether it existed.
+pub fn unset<G: Git, S: Store>(git: &G, store: &S, key: &str) -> Result<bool, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let branch_name = git.branch_name().map_err(Errors::Git)?;
+
+    let Ok(mut branch) = store.get_branch(&branch_name, &repo_name) else {
+        return Ok(false);
+    };
+
+    if branch.data.remove(key.trim()).is_none() {
+        return Ok(false);
+    }
+
+    store
+        .persist_branch(&branch)
+        .map_err(Errors::PersistError)?;
+
+    Ok(true)
+}
+
+/// Context stored for the current branch if any.
+pub fn show<G: Git, S: Store>(git: &G, store: &S) -> Result<Option<Branch>, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let branch_name = git.branch_name().map_err(Errors::Git)?;
+
+    Ok(store.get_branch(&branch_name, &repo_name).ok())
+}
+
+fn current_or_new<G: Git, S: Store>(git: &G, store: &S) -> Result<Branch, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let branch_name = git.branch_name().map_err(Errors::Git)?;
+
+    let branch = store
+        .get_branch(&branch_name, &repo_name)
+        .unwrap_or_else(|_| Branch::new(&branch_name, &repo_name, None, None, None));
+
+    Ok(branch)
+}
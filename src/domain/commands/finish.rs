@@ -0,0 +1,107 @@
+use crate::{
+    domain::{
+        adapters::{CheckoutStatus, Git, MergeStrategy, Store},
+        commands::message::{self, MessageValues},
+        errors::{Errors, GitError},
+        models::Branch,
+    },
+    template_config::TemplateConfig,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finish {
+    /// Branch the current branch is merged into.
+    pub base: String,
+    pub strategy: MergeStrategy,
+    /// Delete the branch once merged.
+    pub delete: bool,
+}
+
+/// Outcome of finishing a branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finished {
+    pub branch: String,
+    pub message: String,
+    /// Archived context that was stored against the branch.
+    pub context: Option<Branch>,
+}
+
+/// Merge the current branch into its base with a templated message & archive its stored context.
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    config: &TemplateConfig,
+    args: Finish,
+) -> Result<Finished, Errors> {
+    if let Some(operation) = git.operation_in_progress().map_err(Errors::Git)? {
+        return Err(Errors::Git(GitError::InProgress { operation }));
+    }
+
+    if git.is_detached().map_err(Errors::Git)? {
+        return Err(Errors::Git(GitError::DetachedHead));
+    }
+
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let branch_name = git.branch_name().map_err(Errors::Git)?;
+
+    if branch_name == args.base {
+        return Err(Errors::ValidationError {
+            message: format!(
+                "Already on base branch '{}', checkout the branch to finish first",
+                args.base
+            ),
+        });
+    }
+
+    let commits = git
+        .log(&format!("{}..HEAD", args.base), None)
+        .map_err(Errors::Git)?;
+
+    if commits.is_empty() {
+        return Err(Errors::ValidationError {
+            message: format!(
+                "No commits on '{}' to merge into '{}'",
+                branch_name, args.base
+            ),
+        });
+    }
+
+    let context = store.get_branch(&branch_name, &repo_name).ok();
+
+    let template = match args.strategy {
+        MergeStrategy::Merge => &config.merge,
+        MergeStrategy::Squash => &config.squash,
+    };
+    let values = MessageValues {
+        source_branch: Some(&branch_name),
+        target_branch: Some(&args.base),
+        commits: &commits,
+        ..MessageValues::default()
+    };
+    let message = message::render(template, values, context.clone(), &config.commit)?;
+
+    git.checkout(&args.base, CheckoutStatus::Existing)
+        .map_err(Errors::Git)?;
+
+    git.merge(&branch_name, &message, args.strategy)
+        .map_err(Errors::Git)?;
+
+    // Archived before deleting so the context isn't left behind as an orphan if deleting fails.
+    let context = context
+        .map(|_| store.archive_branch(&branch_name, &repo_name))
+        .transpose()
+        .map_err(Errors::PersistError)?;
+
+    if args.delete {
+        // Squashed branches aren't recorded as merged so git would refuse a safe delete.
+        let force = args.strategy == MergeStrategy::Squash;
+        git.delete_branch(&branch_name, force)
+            .map_err(Errors::Git)?;
+    }
+
+    Ok(Finished {
+        branch: branch_name,
+        message,
+        context,
+    })
+}
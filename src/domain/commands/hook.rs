@@ -0,0 +1,69 @@
+use crate::{
+    domain::{
+        adapters::{Git, Store},
+        commands::message::{self, MessageValues},
+        errors::Errors,
+    },
+    template_config::TemplateConfig,
+};
+
+/// Rewrite git's default merge commit message through the merge template.
+///
+/// Returns `None` when the message should be left as is, i.e. it isn't a merge or was already customised.
+pub fn prepare_commit_msg<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    config: &TemplateConfig,
+    content: &str,
+    source: Option<&str>,
+) -> Result<Option<String>, Errors> {
+    if source != Some("merge") {
+        return Ok(None);
+    }
+
+    let Some((source_branch, local_branch)) = content.lines().next().and_then(merged_branch) else {
+        log::info!("merge message isn't git's default, leaving as is");
+        return Ok(None);
+    };
+
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    // Detached HEAD has no branch name to merge into.
+    let target_branch = git.branch_name().ok();
+    let commits = git.log("HEAD..MERGE_HEAD", None).map_err(Errors::Git)?;
+    let context = store.get_branch(local_branch, &repo_name).ok();
+
+    let values = MessageValues {
+        source_branch: Some(source_branch),
+        target_branch: target_branch.as_deref(),
+        commits: &commits,
+        ..MessageValues::default()
+    };
+    let message = message::render(&config.merge, values, context, &config.commit)?;
+
+    // Keep git's comments such as the list of resolved conflicts.
+    let comments = content
+        .lines()
+        .filter(|line| line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    match comments.is_empty() {
+        true => Ok(Some(format!("{message}\n"))),
+        false => Ok(Some(format!("{message}\n\n{comments}\n"))),
+    }
+}
+
+/// Merged branch & its local name parsed from git's default merge subject i.e. `Merge branch 'feat' into main`.
+fn merged_branch(subject: &str) -> Option<(&str, &str)> {
+    if let Some(rest) = subject.strip_prefix("Merge branch '") {
+        let name = &rest[..rest.find('\'')?];
+        return Some((name, name));
+    }
+
+    // Remote tracking branches are stored against their local name.
+    let rest = subject.strip_prefix("Merge remote-tracking branch '")?;
+    let name = &rest[..rest.find('\'')?];
+    let local = name.split_once('/').map_or(name, |(_, local)| local);
+
+    Some((name, local))
+}
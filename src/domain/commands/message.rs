@@ -0,0 +1,68 @@
+use crate::{
+    domain::{
+        commands::commit::branch_values,
+        errors::Errors,
+        models::{Branch, GitCommit},
+        template::Templator,
+    },
+    template_config::{CommitConfig, MessageTemplate},
+};
+
+/// Values for the placeholders merge, squash & tag templates support on top of the commit placeholders.
+#[derive(Debug, Clone, Default)]
+pub struct MessageValues<'a> {
+    pub source_branch: Option<&'a str>,
+    pub target_branch: Option<&'a str>,
+    pub version: Option<String>,
+    pub previous_version: Option<String>,
+    /// Listed oldest first as `{commits}`.
+    pub commits: &'a [GitCommit],
+}
+
+/// Render a merge, squash or tag message, any placeholders without a value are removed.
+pub fn render(
+    template: &MessageTemplate,
+    values: MessageValues,
+    branch: Option<Branch>,
+    config: &CommitConfig,
+) -> Result<String, Errors> {
+    let commits = values
+        .commits
+        .iter()
+        .rev()
+        .map(|commit| format!("- {}", commit.subject()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Ticket, scope, link & branch context placeholders are shared with commit templates.
+    let mut placeholders = branch_values(
+        branch,
+        None,
+        None,
+        config.ticket_link.as_deref(),
+        config.ticket_separator.as_deref(),
+    );
+
+    placeholders.extend([
+        (
+            "source_branch".into(),
+            values.source_branch.map(String::from),
+        ),
+        (
+            "target_branch".into(),
+            values.target_branch.map(String::from),
+        ),
+        ("version".into(), values.version),
+        ("previous_version".into(), values.previous_version),
+        ("commits".into(), Some(commits)),
+    ]);
+
+    // Rendered in a single pass so commit subjects containing braces are kept as is.
+    template
+        .content
+        .render(&placeholders)
+        .map_err(|e| Errors::Configuration {
+            message: "Failed attempting to build message".into(),
+            source: e,
+        })
+}
@@ -1,3 +1,16 @@
+pub mod apply_ticket;
+pub mod branch;
+pub mod changelog;
 pub mod checkout;
 pub mod commit;
+pub mod commit_log;
 pub mod context;
+pub mod finish;
+pub mod hook;
+pub mod message;
+pub mod open;
+pub mod release_notes;
+pub mod reword;
+pub mod switch;
+pub mod tag;
+pub mod version;
@@ -0,0 +1,32 @@
+use crate::domain::{
+    adapters::{Git, Store},
+    errors::Errors,
+};
+
+/// Ticket link for the current branch, either stored or rendered from the issue tracker url pattern.
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    ticket_link: Option<&str>,
+) -> Result<String, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let branch_name = git.branch_name().map_err(Errors::Git)?;
+
+    let branch =
+        store
+            .get_branch(&branch_name, &repo_name)
+            .map_err(|_| Errors::ValidationError {
+                message: format!(
+                    "No ticket stored for branch '{branch_name}', add one via 'git-kit context'"
+                ),
+            })?;
+
+    branch
+        .ticket_link(ticket_link)
+        .ok_or_else(|| Errors::ValidationError {
+            message: format!(
+                "No link for ticket '{}', add one via 'git-kit context --link' or configure 'commit.ticket_link'",
+                branch.ticket
+            ),
+        })
+}
@@ -0,0 +1,225 @@
+use regex::Regex;
+use serde::Serialize;
+
+use crate::{
+    domain::{
+        adapters::{Git, Store},
+        commands::changelog::{self, Entry},
+        errors::Errors,
+        parser::CommitParser,
+    },
+    template_config::CommitConfig,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReleaseNotes {
+    pub range: String,
+    /// Tickets ordered by their most recent commit.
+    pub tickets: Vec<TicketNotes>,
+    /// Commits that don't reference any ticket.
+    pub unticketed: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TicketNotes {
+    pub ticket: String,
+    pub link: Option<String>,
+    pub commits: Vec<Entry>,
+}
+
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    config: &CommitConfig,
+    range: &str,
+) -> Result<ReleaseNotes, Errors> {
+    let parser = CommitParser::new(&config.templates).map_err(|e| Errors::Configuration {
+        message: "Failed to parse commit templates".into(),
+        source: e,
+    })?;
+
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let branches = store.get_branches(&repo_name).unwrap_or_else(|e| {
+        log::error!("Failed to retrieve branch tickets: {}", e);
+        vec![]
+    });
+
+    // Branches without a ticket default to the branch name which isn't worth searching for.
+    let tickets = branches
+        .iter()
+        .filter(|b| b.name != format!("{}-{}", repo_name, b.ticket))
+        .flat_map(|b| b.all_tickets())
+        .filter_map(|t| ticket_regex(&t.ticket).map(|regex| (t.ticket, regex)))
+        .collect::<Vec<_>>();
+
+    let links = changelog::ticket_links(store, &repo_name);
+
+    let mut notes = ReleaseNotes {
+        range: range.into(),
+        tickets: vec![],
+        unticketed: vec![],
+    };
+
+    for commit in git.log(range, None).map_err(Errors::Git)? {
+        let parsed = parser.parse(&commit.message);
+
+        // Fallback to any stored branch ticket mentioned when the template has no ticket.
+        let ticket = parsed.as_ref().and_then(|p| p.ticket.clone()).or_else(|| {
+            tickets
+                .iter()
+                .find(|(_, regex)| regex.is_match(&commit.message))
+                .map(|(ticket, _)| ticket.clone())
+        });
+
+        let mut entry = Entry::new(commit, parsed, &links);
+
+        let Some(ticket) = ticket else {
+            notes.unticketed.push(entry);
+            continue;
+        };
+
+        entry.link = entry.link.or_else(|| links.get(&ticket).cloned());
+        entry.ticket = Some(ticket.clone());
+
+        match notes.tickets.iter_mut().find(|t| t.ticket == ticket) {
+            Some(notes) => {
+                notes.link = notes.link.clone().or_else(|| entry.link.clone());
+                notes.commits.push(entry);
+            }
+            None => notes.tickets.push(TicketNotes {
+                link: entry.link.clone(),
+                ticket,
+                commits: vec![entry],
+            }),
+        }
+    }
+
+    Ok(notes)
+}
+
+/// Match a ticket as a whole word so `ABC-1` doesn't match `ABC-12`.
+fn ticket_regex(ticket: &str) -> Option<Regex> {
+    Regex::new(&format!(
+        r"(^|[^\w-]){}([^\w-]|$)",
+        regex::escape(ticket.trim())
+    ))
+    .map_err(|e| log::error!("Invalid ticket pattern '{}': {}", ticket, e))
+    .ok()
+}
+
+impl ReleaseNotes {
+    pub fn to_markdown(&self) -> String {
+        let mut lines = vec![];
+
+        let sections = self
+            .tickets
+            .iter()
+            .map(|t| {
+                let title = match &t.link {
+                    Some(link) => format!("[{}]({link})", t.ticket),
+                    None => t.ticket.clone(),
+                };
+                (title, &t.commits)
+            })
+            .chain(
+                Some(("No ticket".to_string(), &self.unticketed))
+                    .filter(|(_, commits)| !commits.is_empty()),
+            );
+
+        for (title, commits) in sections {
+            lines.push(format!("## {title}"));
+            lines.push("".into());
+
+            for entry in commits {
+                lines.push(format!(
+                    "- {} ({})",
+                    entry.message,
+                    &entry.hash[..entry.hash.len().min(7)]
+                ));
+            }
+
+            lines.push("".into());
+        }
+
+        lines.join("\n").trim_end().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn entry(message: &str) -> Entry {
+        Entry {
+            hash: "abc1234def".into(),
+            author: "author".into(),
+            date: Utc::now(),
+            template: None,
+            scope: None,
+            message: message.into(),
+            ticket: None,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn to_markdown_renders_tickets_with_links_then_unticketed() {
+        let notes = ReleaseNotes {
+            range: "HEAD".into(),
+            tickets: vec![
+                TicketNotes {
+                    ticket: "ABC-1".into(),
+                    link: Some("https://abc/1".into()),
+                    commits: vec![entry("Add"), entry("Fix")],
+                },
+                TicketNotes {
+                    ticket: "ABC-2".into(),
+                    link: None,
+                    commits: vec![entry("Change")],
+                },
+            ],
+            unticketed: vec![entry("Tidy")],
+        };
+
+        let expected = [
+            "## [ABC-1](https://abc/1)",
+            "",
+            "- Add (abc1234)",
+            "- Fix (abc1234)",
+            "",
+            "## ABC-2",
+            "",
+            "- Change (abc1234)",
+            "",
+            "## No ticket",
+            "",
+            "- Tidy (abc1234)",
+        ]
+        .join("\n");
+
+        assert_eq!(expected, notes.to_markdown());
+    }
+
+    #[test]
+    fn ticket_regex_matches_whole_ticket() {
+        let regex = ticket_regex("ABC-1").unwrap();
+
+        assert!(regex.is_match("ABC-1"));
+        assert!(regex.is_match("fix: thing\n\nRefs ABC-1."));
+        assert!(!regex.is_match("fix: ABC-12"));
+        assert!(!regex.is_match("fix: XABC-1"));
+    }
+
+    #[test]
+    fn to_markdown_without_unticketed_omits_section() {
+        let notes = ReleaseNotes {
+            range: "HEAD".into(),
+            tickets: vec![],
+            unticketed: vec![],
+        };
+
+        assert_eq!("", notes.to_markdown());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::domain::{
+    adapters::Git, commands::commit::Commit, errors::Errors, models::GitCommit, template::Templator,
+};
+
+/// Re-render a commit's message with new inputs & rewrite it in place.
+pub fn handler<G: Git>(
+    git: &G,
+    target: &GitCommit,
+    commit: Commit,
+    link: Option<String>,
+) -> Result<String, Errors> {
+    let message = render(&commit, link)?;
+
+    if message.trim() == target.message.trim() {
+        log::info!("message unchanged for commit '{}'", target.hash);
+        return Ok(message);
+    }
+
+    let head = git.show("HEAD").map_err(Errors::Git)?;
+
+    match head.hash == target.hash {
+        true => git.amend_message(&message).map_err(Errors::Git)?,
+        false => {
+            ensure_rewritable(git, &target.hash)?;
+
+            git.reword(&[(target.hash.clone(), message.clone())])
+                .map_err(Errors::Git)?
+        }
+    }
+
+    Ok(message)
+}
+
+/// Ensure commits from `oldest` up to HEAD can be rewritten, the rebase would otherwise flatten merges.
+pub(crate) fn ensure_rewritable<G: Git>(git: &G, oldest: &str) -> Result<(), Errors> {
+    if !git.is_ancestor(oldest).map_err(Errors::Git)? {
+        return Err(Errors::ValidationError {
+            message: format!(
+                "Commit '{oldest}' is not on the current branch, checkout a branch containing it first"
+            ),
+        });
+    }
+
+    let merges = git
+        .merges(&format!("{oldest}..HEAD"))
+        .map_err(Errors::Git)?;
+
+    if let Some(merge) = merges.first() {
+        return Err(Errors::ValidationError {
+            message: format!(
+                "Cannot rewrite commits across merge commit '{merge}', reword them via 'git rebase --rebase-merges' instead"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Render a replacement message for an existing commit.
+pub(crate) fn render(commit: &Commit, link: Option<String>) -> Result<String, Errors> {
+    let mut values = commit.values(None);
+
+    // Parsed link is used in place of branch context which may no longer exist.
+    if link.is_some() {
+        values.insert("link".into(), link);
+    }
+
+    let mut message =
+        commit
+            .template
+            .content
+            .render(&values)
+            .map_err(|e| Errors::Configuration {
+                message: "Failed attempting to build commit message".into(),
+                source: e,
+            })?;
+
+    // Keep any existing body the chosen template has no place for.
+    if let Some(body) = commit.body.as_ref().filter(|b| !b.trim().is_empty()) {
+        if !commit.template.contains_placeholder("body") {
+            message = format!("{message}\n\n{}", body.trim());
+        }
+    }
+
+    Ok(message)
+}
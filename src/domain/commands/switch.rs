@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    adapters::{CheckoutStatus, Git, Store},
+    errors::Errors,
+    models::Branch,
+};
+
+/// Local branch along with any context stored against it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Switch {
+    pub name: String,
+    pub context: Option<Branch>,
+}
+
+/// Local branches other than the current branch, most recently used first.
+pub fn branches<G: Git, S: Store>(git: &G, store: &S) -> Result<Vec<Switch>, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    // Detached HEAD has no current branch to exclude.
+    let current = git.branch_name().ok();
+
+    let stored = store
+        .get_branches(&repo_name)
+        .map_err(Errors::PersistError)?;
+
+    let prefix = format!("{}-", repo_name.trim());
+    let mut branches: Vec<Switch> = git
+        .branches()
+        .map_err(Errors::Git)?
+        .into_iter()
+        .filter(|name| current.as_ref() != Some(name))
+        .map(|name| Switch {
+            context: stored
+                .iter()
+                .find(|branch| branch.name.strip_prefix(&prefix) == Some(name.as_str()))
+                .cloned(),
+            name,
+        })
+        .collect();
+
+    // Stable sort keeps git's ordering for branches never used.
+    branches.sort_by_key(|switch| std::cmp::Reverse(last_used(switch)));
+
+    Ok(branches)
+}
+
+/// Checkout an existing local branch recording when it was last used.
+pub fn handler<G: Git, S: Store>(git: &G, store: &S, name: &str) -> Result<Option<Branch>, Errors> {
+    git.checkout(name, CheckoutStatus::Existing)
+        .map_err(Errors::Git)?;
+
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+
+    let Ok(mut branch) = store.get_branch(name, &repo_name) else {
+        return Ok(None);
+    };
+
+    branch.last_used = Some(Utc::now());
+    store
+        .persist_branch(&branch)
+        .map_err(Errors::PersistError)?;
+
+    Ok(Some(branch))
+}
+
+fn last_used(switch: &Switch) -> Option<DateTime<Utc>> {
+    switch.context.as_ref().and_then(|branch| branch.last_used)
+}
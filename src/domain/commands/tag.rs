@@ -0,0 +1,73 @@
+use crate::{
+    domain::{
+        adapters::Git,
+        commands::{
+            message::{self, MessageValues},
+            version,
+        },
+        errors::Errors,
+        models::Version,
+    },
+    template_config::TemplateConfig,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    /// Version to tag, defaults to the suggested next version.
+    pub version: Option<Version>,
+    /// Message used as is rather than rendering the tag template.
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tagged {
+    pub version: Version,
+    pub previous: Option<Version>,
+    pub message: String,
+}
+
+/// Create an annotated version tag on HEAD with a message listing commits since the previous version.
+pub fn handler<G: Git>(git: &G, config: &TemplateConfig, args: Tag) -> Result<Tagged, Errors> {
+    let previous = version::current(git)?;
+
+    let version = match args.version {
+        Some(version) => version,
+        None => {
+            let suggestion = version::next(git, &config.commit)?;
+            suggestion.next.ok_or_else(|| Errors::ValidationError {
+                message: format!(
+                    "No release needed, none of the {} commits since the latest version warrant a version bump",
+                    suggestion.commits
+                ),
+            })?
+        }
+    };
+
+    let message = match args.message {
+        Some(message) => message,
+        None => {
+            let range = match &previous {
+                Some(previous) => format!("{previous}..HEAD"),
+                None => "HEAD".into(),
+            };
+            let commits = git.log(&range, None).map_err(Errors::Git)?;
+
+            let values = MessageValues {
+                version: Some(version.to_string()),
+                previous_version: previous.as_ref().map(Version::to_string),
+                commits: &commits,
+                ..MessageValues::default()
+            };
+            message::render(&config.tag, values, None, &config.commit)?
+        }
+    };
+
+    git.create_tag(&version.to_string(), &message)
+        .map_err(Errors::Git)?;
+
+    Ok(Tagged {
+        version,
+        previous,
+        message,
+    })
+}
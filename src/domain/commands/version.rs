@@ -0,0 +1,67 @@
+use crate::{
+    domain::{
+        adapters::Git,
+        errors::Errors,
+        models::{Bump, Version},
+        parser::CommitParser,
+    },
+    template_config::CommitConfig,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextVersion {
+    /// Latest version tag reachable from HEAD.
+    pub current: Option<Version>,
+    /// Suggested version, `None` if no commits warrant a release.
+    pub next: Option<Version>,
+    pub bump: Option<Bump>,
+    /// Number of commits since the current version.
+    pub commits: usize,
+}
+
+/// Suggest the next version from commits since the latest version tag.
+pub fn next<G: Git>(git: &G, config: &CommitConfig) -> Result<NextVersion, Errors> {
+    let parser = CommitParser::new(&config.templates).map_err(|e| Errors::Configuration {
+        message: "Failed to parse commit templates".into(),
+        source: e,
+    })?;
+
+    let current = current(git)?;
+
+    let range = match &current {
+        Some(version) => format!("{version}..HEAD"),
+        None => "HEAD".into(),
+    };
+
+    let commits = git.log(&range, None).map_err(Errors::Git)?;
+
+    let bump = commits
+        .iter()
+        .filter_map(|commit| {
+            if config.bumps.is_breaking(&commit.message) {
+                return Some(Bump::Major);
+            }
+
+            let parsed = parser.parse(&commit.message)?;
+            config.bumps.template_bump(&parsed.template)
+        })
+        .max();
+
+    log::info!("suggested '{:?}' bump from {} commits", bump, commits.len());
+
+    let next = bump.map(|bump| current.clone().unwrap_or_default().bump(bump));
+
+    Ok(NextVersion {
+        current,
+        next,
+        bump,
+        commits: commits.len(),
+    })
+}
+
+/// Latest version tag reachable from HEAD.
+pub fn current<G: Git>(git: &G) -> Result<Option<Version>, Errors> {
+    let tags = git.tags().map_err(Errors::Git)?;
+
+    Ok(tags.iter().find_map(|tag| Version::parse(tag)))
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::domain::adapters::GitOperation;
+
 #[derive(Error, Debug)]
 pub enum Errors {
     #[error(transparent)]
@@ -44,14 +46,99 @@ pub enum GitError {
     #[error("Failed retrieve the current git root directory")]
     RootDirectory,
 
+    #[error("Failed to retrieve the current git directory")]
+    GitDirectory,
+
     #[error("Failed to checkout branch {name:?}")]
     Checkout { name: String },
 
+    #[error("Failed to rename branch {from:?} to {to:?}")]
+    Rename { from: String, to: String },
+
+    #[error("Failed to retrieve branches")]
+    Branches,
+
+    #[error("Failed to merge branch {branch:?}, resolve any conflicts & commit or run 'git merge --abort'")]
+    Merge { branch: String },
+
+    #[error("Failed to squash branch {branch:?}, resolve any conflicts & commit or run 'git reset --merge'")]
+    Squash { branch: String },
+
+    #[error("Failed to delete branch {name:?}")]
+    DeleteBranch { name: String },
+
     #[error("Failed to apply commit")]
     Commit,
 
+    #[error("Failed to retrieve staged changes")]
+    StagedFiles,
+
+    #[error("Failed to retrieve commits for revision range {range:?}")]
+    Log { range: String },
+
+    #[error("Failed to find commit for revision {rev:?}")]
+    Revision { rev: String },
+
+    #[error("Failed to reword commit {rev:?}, resolve any conflicts & run 'git rebase --continue' or 'git rebase --abort'")]
+    Reword { rev: String },
+
+    #[error("Failed to find a common ancestor with {base:?}")]
+    MergeBase { base: String },
+
+    #[error("Failed to retrieve tags")]
+    Tags,
+
+    #[error("Failed to create tag {name:?}")]
+    Tag { name: String },
+
+    #[error("Failed to stage tracked changes")]
+    Stage,
+
+    #[error("Failed to retrieve the current git repository state")]
+    State,
+
+    #[error("No changes staged for commit, stage changes via 'git add' or use '--all' to stage all tracked changes")]
+    NothingStaged,
+
+    #[error("A {operation} is in progress, please complete or abort the {operation} first")]
+    InProgress { operation: GitOperation },
+
+    #[error("HEAD is detached from a branch, please checkout a branch first")]
+    DetachedHead,
+
+    #[error("Cannot resolve the current branch while a bisect is in progress, run 'git bisect reset' first")]
+    Bisecting,
+
     #[error("Validation error occurred {message}")]
     Validation { message: String },
+
+    #[error("{message}, {}", .command.reason())]
+    Command {
+        message: String,
+        command: CommandError,
+    },
+}
+
+/// Git cli command which exited unsuccessfully.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("'git {}' exited with {}", .args.join(" "), .code.map(|c| format!("code {c}")).unwrap_or_else(|| "no code".into()))]
+pub struct CommandError {
+    pub args: Vec<String>,
+    /// Exit code, `None` when terminated by a signal.
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CommandError {
+    /// Git's own explanation for failing i.e. "fatal: a branch named 'feat' already exists".
+    pub fn reason(&self) -> String {
+        match (self.stderr.trim(), self.stdout.trim()) {
+            ("", "") => self.to_string(),
+            ("", stdout) => stdout.into(),
+            (stderr, _) => stderr.into(),
+        }
+    }
 }
 
 #[derive(Error, Debug)]
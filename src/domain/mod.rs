@@ -2,4 +2,5 @@ pub mod adapters;
 pub mod commands;
 pub mod errors;
 pub mod models;
+pub mod parser;
 pub mod template;
@@ -1,13 +1,34 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::string::{percent_encode, OptionStr};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Branch {
     pub name: String,
+    /// Repository the branch belongs to, unknown for contexts stored before it was recorded.
+    pub repo: Option<String>,
+    /// Primary ticket related to the branch.
     pub ticket: String,
     pub created: DateTime<Utc>,
-    pub data: Option<Vec<u8>>,
+    /// Arbitrary key/value context available as template placeholders i.e. `{reviewer}`.
+    pub data: BTreeMap<String, String>,
     pub link: Option<String>,
     pub scope: Option<String>,
+    /// Additional tickets related to the branch after the primary ticket.
+    pub tickets: Vec<Ticket>,
+    /// Last time the branch was checked out or switched to via git-kit.
+    pub last_used: Option<DateTime<Utc>>,
+    /// When the branch was finished, archived context is kept for reports.
+    pub archived: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Ticket {
+    pub ticket: String,
+    pub link: Option<String>,
 }
 
 impl Branch {
@@ -20,13 +41,71 @@ impl Branch {
     ) -> Branch {
         Branch {
             name: format!("{}-{}", repo.trim(), name.trim()),
+            repo: Some(repo.trim().into()),
             created: Utc::now(),
             ticket: ticket.unwrap_or_else(|| name.into()),
             link,
             scope,
-            data: None,
+            data: BTreeMap::new(),
+            tickets: vec![],
+            last_used: None,
+            archived: None,
         }
     }
+
+    /// Branch related to several tickets, the first being the primary ticket with links matched by position.
+    pub fn with_tickets(
+        name: &str,
+        repo: &str,
+        tickets: Vec<String>,
+        links: Vec<String>,
+        scope: Option<String>,
+    ) -> Branch {
+        let mut tickets = tickets.into_iter();
+        let mut links = links.into_iter();
+
+        let mut branch = Branch::new(
+            name,
+            repo,
+            tickets.next(),
+            links.next().none_if_empty(),
+            scope,
+        );
+        branch.tickets = tickets
+            .map(|ticket| Ticket {
+                ticket,
+                link: links.next().none_if_empty(),
+            })
+            .collect();
+
+        branch
+    }
+
+    /// Primary ticket followed by any additional tickets.
+    pub fn all_tickets(&self) -> Vec<Ticket> {
+        let primary = Ticket {
+            ticket: self.ticket.clone(),
+            link: self.link.clone(),
+        };
+
+        std::iter::once(primary)
+            .chain(self.tickets.iter().cloned())
+            .collect()
+    }
+
+    /// Stored link, otherwise one rendered from the issue tracker url pattern.
+    pub fn ticket_link(&self, pattern: Option<&str>) -> Option<String> {
+        self.link
+            .clone()
+            .none_if_empty()
+            .or_else(|| pattern.map(|pattern| render_ticket_link(pattern, &self.ticket)))
+    }
+}
+
+/// Render an issue tracker url pattern i.e. `https://tracker/browse/{ticket_num}` for a ticket.
+/// The ticket is percent-encoded as it's user input placed within a url.
+pub fn render_ticket_link(pattern: &str, ticket: &str) -> String {
+    pattern.replace("{ticket_num}", &percent_encode(ticket.trim()))
 }
 
 #[cfg(test)]
@@ -61,11 +140,120 @@ mod tests {
         assert_eq!(branch.scope.unwrap(), scope);
         assert_eq!(branch.link.unwrap(), link);
         assert!(branch.created > now);
-        assert_eq!(branch.data, None);
+        assert!(branch.data.is_empty());
 
         Ok(())
     }
 
+    #[test]
+    fn ticket_link_prefers_stored_link() {
+        let branch = Branch {
+            link: Some("https://stored/ABC-1".into()),
+            ..fake_branch("ABC-1")
+        };
+
+        let link = branch.ticket_link(Some("https://tracker/browse/{ticket_num}"));
+
+        assert_eq!(Some("https://stored/ABC-1".into()), link);
+    }
+
+    #[test]
+    fn ticket_link_renders_pattern_without_stored_link() {
+        for link in [None, Some("".into())] {
+            let branch = Branch {
+                link,
+                ..fake_branch("ABC-1")
+            };
+
+            let rendered = branch.ticket_link(Some("https://tracker/browse/{ticket_num}"));
+
+            assert_eq!(Some("https://tracker/browse/ABC-1".into()), rendered);
+            assert_eq!(None, branch.ticket_link(None));
+        }
+    }
+
+    #[test]
+    fn render_ticket_link_percent_encodes_ticket() {
+        assert_eq!(
+            "https://tracker/browse/ABC-1%26calc%7C",
+            render_ticket_link("https://tracker/browse/{ticket_num}", " ABC-1&calc| ")
+        );
+    }
+
+    #[test]
+    fn all_tickets_starts_with_primary_ticket() {
+        let branch = Branch {
+            link: Some("https://issues/ABC-1".into()),
+            tickets: vec![Ticket {
+                ticket: "ABC-2".into(),
+                link: None,
+            }],
+            ..fake_branch("ABC-1")
+        };
+
+        let tickets = branch
+            .all_tickets()
+            .into_iter()
+            .map(|t| (t.ticket, t.link))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (
+                    "ABC-1".to_string(),
+                    Some("https://issues/ABC-1".to_string())
+                ),
+                ("ABC-2".to_string(), None),
+            ],
+            tickets
+        );
+    }
+
+    #[test]
+    fn with_tickets_matches_links_by_position() {
+        let branch = Branch::with_tickets(
+            "name",
+            "repo",
+            vec!["ABC-1".into(), "ABC-2".into(), "ABC-3".into()],
+            vec!["https://issues/ABC-1".into(), "https://issues/ABC-2".into()],
+            None,
+        );
+
+        assert_eq!("ABC-1", branch.ticket);
+        assert_eq!(Some("https://issues/ABC-1".into()), branch.link);
+        assert_eq!(
+            vec![
+                Ticket {
+                    ticket: "ABC-2".into(),
+                    link: Some("https://issues/ABC-2".into()),
+                },
+                Ticket {
+                    ticket: "ABC-3".into(),
+                    link: None,
+                },
+            ],
+            branch.tickets
+        );
+    }
+
+    #[test]
+    fn with_tickets_without_tickets_defaults_to_name() {
+        let branch = Branch::with_tickets("name", "repo", vec![], vec![], None);
+
+        assert_eq!("name", branch.ticket);
+        assert!(branch.tickets.is_empty());
+    }
+
+    fn fake_branch(ticket: &str) -> Branch {
+        Branch::new(
+            &Faker.fake::<String>(),
+            "repo",
+            Some(ticket.into()),
+            None,
+            None,
+        )
+    }
+
     #[test]
     fn creating_branch_without_ticket_defaults_to_name() -> anyhow::Result<()> {
         // Arrange
@@ -80,7 +268,7 @@ mod tests {
         assert_eq!(branch.name, format!("{}-{}", &repo, &name));
         assert_eq!(branch.ticket, name);
         assert!(branch.created > now);
-        assert_eq!(branch.data, None);
+        assert!(branch.data.is_empty());
 
         Ok(())
     }
@@ -100,7 +288,7 @@ mod tests {
         assert_eq!(branch.name, format!("{}-{}", &repo.trim(), &name.trim()));
         assert_eq!(branch.ticket, ticket);
         assert!(branch.created > now);
-        assert_eq!(branch.data, None);
+        assert!(branch.data.is_empty());
 
         Ok(())
     }
@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitStatus {
+    Pending,
+    Committed,
+    Failed,
+}
+
+impl From<CommitStatus> for String {
+    fn from(status: CommitStatus) -> Self {
+        match status {
+            CommitStatus::Pending => "PENDING".into(),
+            CommitStatus::Committed => "COMMITTED".into(),
+            CommitStatus::Failed => "FAILED".into(),
+        }
+    }
+}
+
+impl TryInto<CommitStatus> for String {
+    type Error = anyhow::Error;
+
+    fn try_into(self) -> Result<CommitStatus, Self::Error> {
+        match self.as_str() {
+            "PENDING" => Ok(CommitStatus::Pending),
+            "COMMITTED" => Ok(CommitStatus::Committed),
+            "FAILED" => Ok(CommitStatus::Failed),
+            value => anyhow::bail!("'{}' is not a valid CommitStatus", value),
+        }
+    }
+}
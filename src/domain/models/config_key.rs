@@ -1,3 +1,5 @@
+use std::fmt;
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum ConfigKey {
     User(String),
@@ -18,9 +20,9 @@ impl ConfigKey {
     }
 }
 
-impl ToString for ConfigKey {
-    fn to_string(&self) -> String {
-        self.to_owned().into()
+impl fmt::Display for ConfigKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from(self.to_owned()))
     }
 }
 
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+
+/// Commit retrieved from the repository history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitCommit {
+    pub hash: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    /// Full commit message including the subject line.
+    pub message: String,
+}
+
+impl GitCommit {
+    /// First line of the commit message.
+    pub fn subject(&self) -> &str {
+        self.message.lines().next().unwrap_or_default().trim()
+    }
+
+    /// Abbreviated commit hash.
+    pub fn short_hash(&self) -> &str {
+        &self.hash[..self.hash.len().min(7)]
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::CommitStatus;
+
+/// Inputs provided when rendering a commit message.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CommitInputs {
+    pub ticket: Option<String>,
+    pub scope: Option<String>,
+    pub message: Option<String>,
+    pub body: Option<String>,
+    #[serde(default)]
+    pub footers: Vec<(String, String)>,
+}
+
+/// Rendered commit message persisted so it can be recovered after a failed commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitHistory {
+    pub id: String,
+    pub repo: String,
+    pub branch: String,
+    pub template: String,
+    pub inputs: CommitInputs,
+    pub message: String,
+    pub status: CommitStatus,
+    pub created: DateTime<Utc>,
+}
+
+impl CommitHistory {
+    pub fn new(
+        repo: &str,
+        branch: &str,
+        template: &str,
+        inputs: CommitInputs,
+        message: &str,
+    ) -> CommitHistory {
+        CommitHistory {
+            id: Uuid::new_v4().to_string(),
+            repo: repo.trim().into(),
+            branch: branch.trim().into(),
+            template: template.into(),
+            inputs,
+            message: message.into(),
+            status: CommitStatus::Pending,
+            created: Utc::now(),
+        }
+    }
+
+    /// First line of the rendered message.
+    pub fn subject(&self) -> &str {
+        self.message.lines().next().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, Faker};
+
+    use super::*;
+
+    #[test]
+    fn creating_history_populates_correctly() {
+        // Arrange
+        let now = Utc::now();
+        let repo = Faker.fake::<String>();
+        let branch = format!("{}\n", Faker.fake::<String>());
+        let template = Faker.fake::<String>();
+        let inputs = CommitInputs {
+            message: Some(Faker.fake()),
+            ..CommitInputs::default()
+        };
+
+        // Act
+        let history =
+            CommitHistory::new(&repo, &branch, &template, inputs.clone(), "subject\nbody");
+
+        // Assert
+        assert_eq!(history.repo, repo.trim());
+        assert_eq!(history.branch, branch.trim());
+        assert_eq!(history.template, template);
+        assert_eq!(history.inputs, inputs);
+        assert_eq!(history.status, CommitStatus::Pending);
+        assert_eq!(history.subject(), "subject");
+        assert!(history.created >= now);
+    }
+}
@@ -1,10 +1,18 @@
 mod branch;
+mod commit_status;
 mod config;
 mod config_key;
 mod config_status;
+mod git_commit;
+mod history;
 pub mod path;
+mod version;
 
-pub use branch::Branch;
+pub use branch::{render_ticket_link, Branch, Ticket};
+pub use commit_status::CommitStatus;
 pub use config::Config;
 pub use config_key::ConfigKey;
 pub use config_status::ConfigStatus;
+pub use git_commit::GitCommit;
+pub use history::{CommitHistory, CommitInputs};
+pub use version::{Bump, Version};
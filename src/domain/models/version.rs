@@ -0,0 +1,134 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Semantic version increment ordered from least to most significant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// Semantic version parsed from a tag such as `v1.2.3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    /// Any prefix preceding the version i.e. `v`.
+    pub prefix: String,
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    /// Parse a release tag, any pre-release or build metadata is not considered a release.
+    pub fn parse(tag: &str) -> Option<Version> {
+        let tag = tag.trim();
+        let (prefix, version) = tag.split_at(tag.find(|c: char| c.is_ascii_digit())?);
+
+        let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+        let version = Version {
+            prefix: prefix.into(),
+            major: parts.next()??,
+            minor: parts.next()??,
+            patch: parts.next()??,
+        };
+
+        match parts.next().is_none() && matches!(prefix, "" | "v") {
+            true => Some(version),
+            false => None,
+        }
+    }
+
+    pub fn bump(&self, bump: Bump) -> Version {
+        let (major, minor, patch) = match bump {
+            Bump::Major => (self.major + 1, 0, 0),
+            Bump::Minor => (self.major, self.minor + 1, 0),
+            Bump::Patch => (self.major, self.minor, self.patch + 1),
+        };
+
+        Version {
+            prefix: self.prefix.clone(),
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Version {
+            prefix: "v".into(),
+            major: 0,
+            minor: 0,
+            patch: 0,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}.{}.{}",
+            self.prefix, self.major, self.minor, self.patch
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_versions() {
+        let version = Version::parse("v1.2.3").unwrap();
+        assert_eq!(version.to_string(), "v1.2.3");
+        assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+
+        assert_eq!(Version::parse("10.0.1").unwrap().to_string(), "10.0.1");
+    }
+
+    #[test]
+    fn parse_invalid_versions_are_none() {
+        for tag in [
+            "v1.2",
+            "v1.2.3-beta.1",
+            "release-1.2.3",
+            "1.2.3.4",
+            "latest",
+            "",
+        ] {
+            assert_eq!(None, Version::parse(tag), "{tag}");
+        }
+    }
+
+    #[test]
+    fn bump_resets_less_significant_parts() {
+        let version = Version::parse("v1.2.3").unwrap();
+
+        assert_eq!(version.bump(Bump::Major).to_string(), "v2.0.0");
+        assert_eq!(version.bump(Bump::Minor).to_string(), "v1.3.0");
+        assert_eq!(version.bump(Bump::Patch).to_string(), "v1.2.4");
+    }
+
+    #[test]
+    fn bumps_are_ordered_by_significance() {
+        assert!(Bump::Major > Bump::Minor);
+        assert!(Bump::Minor > Bump::Patch);
+    }
+}
@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::template_config::Template;
+
+/// Placeholders spanning multiple lines which can't be parsed back out of a single line.
+const MULTI_LINE: [&str; 2] = ["body", "footers"];
+
+/// Values parsed back out of a commit message rendered from a template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ParsedCommit {
+    /// Name of the matching template.
+    pub template: String,
+    pub ticket: Option<String>,
+    pub scope: Option<String>,
+    pub link: Option<String>,
+    pub message: Option<String>,
+    /// Remaining lines after the subject that weren't matched by the template.
+    pub body: Option<String>,
+}
+
+impl ParsedCommit {
+    /// Message not matching any template, its subject & remaining lines are kept as the message & body.
+    pub fn unmatched(message: &str) -> ParsedCommit {
+        let message = message.trim();
+        let (subject, body) = message.split_once('\n').unwrap_or((message, ""));
+
+        ParsedCommit {
+            message: Some(subject.trim().to_string()).filter(|m| !m.is_empty()),
+            body: Some(body.trim().to_string()).filter(|b| !b.is_empty()),
+            ..ParsedCommit::default()
+        }
+    }
+}
+
+struct TemplatePattern {
+    name: String,
+    subject: Regex,
+    lines: Vec<Regex>,
+    /// Literal characters within the subject used to prefer the most specific template.
+    specificity: usize,
+}
+
+/// Classifies commit messages by matching them against template contents.
+pub struct CommitParser {
+    patterns: Vec<TemplatePattern>,
+}
+
+impl CommitParser {
+    pub fn new(templates: &HashMap<String, Template>) -> anyhow::Result<CommitParser> {
+        let mut patterns = templates
+            .iter()
+            .filter_map(|(name, template)| {
+                let mut lines = template
+                    .content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty());
+
+                let subject = lines.next()?;
+
+                Some((name, subject, lines.collect::<Vec<_>>()))
+            })
+            .map(|(name, subject, lines)| {
+                let (subject, specificity) = line_regex(subject)
+                    .with_context(|| format!("Failed to parse template '{name}'"))?;
+
+                let lines = lines
+                    .into_iter()
+                    .filter(|line| has_single_line_placeholder(line))
+                    .map(|line| line_regex(line).map(|(regex, _)| regex))
+                    .collect::<anyhow::Result<Vec<_>>>()
+                    .with_context(|| format!("Failed to parse template '{name}'"))?;
+
+                Ok(TemplatePattern {
+                    name: name.clone(),
+                    subject,
+                    lines,
+                    specificity,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        patterns.sort_by(|a, b| {
+            b.specificity
+                .cmp(&a.specificity)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Ok(CommitParser { patterns })
+    }
+
+    /// Parse a commit message using the most specific template with a matching subject line.
+    pub fn parse(&self, message: &str) -> Option<ParsedCommit> {
+        let mut lines = message.lines().skip_while(|line| line.trim().is_empty());
+
+        let subject = lines.next()?.trim();
+        let mut lines = lines.map(Some).collect::<Vec<_>>();
+
+        let pattern = self.patterns.iter().find(|p| p.subject.is_match(subject))?;
+
+        let mut values: HashMap<String, String> = HashMap::new();
+        capture_into(&pattern.subject, subject, &mut values);
+
+        for regex in &pattern.lines {
+            let matched = lines
+                .iter_mut()
+                .find(|line| line.is_some_and(|l| regex.is_match(l.trim())));
+
+            if let Some(line) = matched {
+                capture_into(regex, line.unwrap_or_default().trim(), &mut values);
+                *line = None;
+            }
+        }
+
+        let body = lines
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_string();
+
+        // Adjacent placeholders are ambiguous when only one was provided, i.e. `{ticket_num} {link}`.
+        if !values.contains_key("ticket_num") {
+            if let Some(link) = values.remove("link") {
+                let key = if link.contains("://") {
+                    "link"
+                } else {
+                    "ticket_num"
+                };
+                values.insert(key.into(), link);
+            }
+        }
+
+        Some(ParsedCommit {
+            template: pattern.name.clone(),
+            ticket: values.remove("ticket_num"),
+            scope: values.remove("scope"),
+            link: values.remove("link"),
+            message: values.remove("message"),
+            body: Some(body).filter(|b| !b.is_empty()),
+        })
+    }
+}
+
+fn capture_into(regex: &Regex, line: &str, values: &mut HashMap<String, String>) {
+    let Some(captures) = regex.captures(line) else {
+        return;
+    };
+
+    for name in regex.capture_names().flatten() {
+        if let Some(value) = captures.name(name) {
+            let value = value.as_str().trim();
+            if !value.is_empty() {
+                values
+                    .entry(name.to_string())
+                    .or_insert_with(|| value.to_string());
+            }
+        }
+    }
+}
+
+fn placeholder_regex() -> Regex {
+    // Optional surrounding brackets are removed along with the placeholder when no value is provided.
+    Regex::new(r"(?P<open>[\(\[\{])?\{(?P<name>\w+)\}(?P<close>[\)\]\}])?")
+        .expect("Valid placeholder regex")
+}
+
+fn has_single_line_placeholder(line: &str) -> bool {
+    placeholder_regex()
+        .captures_iter(line)
+        .any(|c| !MULTI_LINE.contains(&&c["name"]))
+}
+
+fn is_pair(open: &str, close: &str) -> bool {
+    matches!((open, close), ("(", ")") | ("[", "]") | ("{", "}"))
+}
+
+/// Literal text with any whitespace loosened as empty placeholders are removed along with trailing spaces.
+fn literal_regex(literal: &str) -> (String, usize) {
+    let pattern = literal
+        .split_whitespace()
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(r"\s*");
+
+    let pattern = match (literal.starts_with(char::is_whitespace), pattern.is_empty()) {
+        (_, true) if !literal.is_empty() => r"\s*".to_string(),
+        (true, false) => format!(r"\s*{pattern}"),
+        _ => pattern,
+    };
+
+    let pattern = match literal.ends_with(char::is_whitespace) && !literal.trim().is_empty() {
+        true => format!(r"{pattern}\s*"),
+        false => pattern,
+    };
+
+    let specificity = literal.chars().filter(|c| !c.is_whitespace()).count();
+
+    (pattern, specificity)
+}
+
+/// Convert a single template line into an anchored regex capturing each placeholder.
+fn line_regex(line: &str) -> anyhow::Result<(Regex, usize)> {
+    let mut pattern = String::from("^");
+    let mut specificity = 0;
+    let mut names: Vec<String> = vec![];
+    let mut position = 0;
+
+    let placeholders = placeholder_regex();
+    let captures = placeholders.captures_iter(line).collect::<Vec<_>>();
+
+    for (index, capture) in captures.iter().enumerate() {
+        let whole = capture.get(0).expect("Capture group 0 always exists");
+        let open = capture.name("open").map(|m| m.as_str());
+        let close = capture.name("close").map(|m| m.as_str());
+        let name = &capture["name"];
+
+        let (literal, count) = literal_regex(&line[position..whole.start()]);
+        pattern.push_str(&literal);
+        specificity += count;
+        position = whole.end();
+
+        let value = match MULTI_LINE.contains(&name) || names.iter().any(|n| n == name) {
+            true => ".*?".to_string(),
+            false => {
+                names.push(name.to_string());
+                format!("(?P<{name}>.+?)")
+            }
+        };
+
+        let group = match (open, close) {
+            (Some(open), Some(close)) if is_pair(open, close) => format!(
+                r"(?:{}{value}{}\s*)?",
+                regex::escape(open),
+                regex::escape(close)
+            ),
+            _ => {
+                let open = open.map(regex::escape).unwrap_or_default();
+                let close = close.map(regex::escape).unwrap_or_default();
+
+                // Bare placeholders followed by a space consume it, mirroring how empty values are removed.
+                let rest = &line[position..];
+                let last = index == captures.len() - 1 && rest.trim().is_empty();
+                let separator = match rest.starts_with(char::is_whitespace) && !last {
+                    true => r"\s+",
+                    false => "",
+                };
+
+                format!("{open}(?:{value}{separator})?{close}")
+            }
+        };
+
+        pattern.push_str(&group);
+    }
+
+    let (literal, count) = literal_regex(&line[position..]);
+    pattern.push_str(&literal);
+    specificity += count;
+    pattern.push('$');
+
+    let regex = Regex::new(&pattern).with_context(|| format!("Invalid template line '{line}'"))?;
+
+    Ok((regex, specificity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::template::Templator;
+
+    fn template(content: &str) -> Template {
+        Template {
+            description: "description".into(),
+            content: content.into(),
+            footers: vec![],
+        }
+    }
+
+    fn default_templates() -> HashMap<String, Template> {
+        HashMap::from([
+            ("bug".into(), template("[{ticket_num}] 🐛 {message}\n")),
+            ("feat".into(), template("[{ticket_num}] ✨ {message}\n")),
+            ("plain".into(), template("{message}")),
+        ])
+    }
+
+    fn conventional_templates() -> HashMap<String, Template> {
+        HashMap::from([
+            (
+                "fix".into(),
+                template("fix({scope}): {message}\n\n{body}\n\nIssue: {ticket_num} {link}\n"),
+            ),
+            (
+                "feat".into(),
+                template("feat({scope}): {message}\n\n{body}\n\nIssue: {ticket_num} {link}\n"),
+            ),
+        ])
+    }
+
+    fn render(content: &str, values: [(&str, Option<&str>); 6]) -> String {
+        let mut message = content.to_string();
+        for (name, value) in values {
+            message = message
+                .replace_or_remove(name, value.map(String::from))
+                .unwrap();
+        }
+        message
+    }
+
+    #[test]
+    fn parse_default_template_with_ticket() {
+        let parser = CommitParser::new(&default_templates()).unwrap();
+
+        let parsed = parser.parse("[ABC-123] 🐛 Fix the thing").unwrap();
+
+        assert_eq!(
+            parsed,
+            ParsedCommit {
+                template: "bug".into(),
+                ticket: Some("ABC-123".into()),
+                message: Some("Fix the thing".into()),
+                ..ParsedCommit::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_default_template_with_removed_ticket() {
+        let parser = CommitParser::new(&default_templates()).unwrap();
+
+        let parsed = parser.parse("✨ Add the thing").unwrap();
+
+        assert_eq!(parsed.template, "feat");
+        assert_eq!(parsed.ticket, None);
+        assert_eq!(parsed.message, Some("Add the thing".into()));
+    }
+
+    #[test]
+    fn parse_prefers_most_specific_template() {
+        let parser = CommitParser::new(&default_templates()).unwrap();
+
+        assert_eq!(parser.parse("Something else").unwrap().template, "plain");
+        assert_eq!(parser.parse("🐛 Bug").unwrap().template, "bug");
+    }
+
+    #[test]
+    fn parse_unmatched_message_returns_none() {
+        let parser = CommitParser::new(&conventional_templates()).unwrap();
+
+        assert_eq!(None, parser.parse("Merge branch 'main'"));
+        assert_eq!(None, parser.parse(""));
+    }
+
+    #[test]
+    fn parse_conventional_template_with_all_values() {
+        let parser = CommitParser::new(&conventional_templates()).unwrap();
+        let content = &conventional_templates()["fix"].content;
+
+        let message = render(
+            content,
+            [
+                ("ticket_num", Some("ABC-123")),
+                ("scope", Some("cli")),
+                ("link", Some("https://issues/ABC-123")),
+                ("message", Some("Fix the thing")),
+                ("body", Some("Some body\nover lines")),
+                ("footers", None),
+            ],
+        );
+
+        let parsed = parser.parse(&message).unwrap();
+
+        assert_eq!(
+            parsed,
+            ParsedCommit {
+                template: "fix".into(),
+                ticket: Some("ABC-123".into()),
+                scope: Some("cli".into()),
+                link: Some("https://issues/ABC-123".into()),
+                message: Some("Fix the thing".into()),
+                body: Some("Some body\nover lines".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_conventional_template_with_removed_values() {
+        let parser = CommitParser::new(&conventional_templates()).unwrap();
+        let content = &conventional_templates()["feat"].content;
+
+        let message = render(
+            content,
+            [
+                ("ticket_num", None),
+                ("scope", None),
+                ("link", Some("https://issues/ABC-123")),
+                ("message", Some("Add the thing")),
+                ("body", None),
+                ("footers", None),
+            ],
+        );
+
+        let parsed = parser.parse(&message).unwrap();
+
+        assert_eq!(
+            parsed,
+            ParsedCommit {
+                template: "feat".into(),
+                link: Some("https://issues/ABC-123".into()),
+                message: Some("Add the thing".into()),
+                ..ParsedCommit::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_conventional_template_with_only_ticket() {
+        let parser = CommitParser::new(&conventional_templates()).unwrap();
+
+        let parsed = parser.parse("fix: Fix\n\nIssue: ABC-123").unwrap();
+
+        assert_eq!(parsed.ticket, Some("ABC-123".into()));
+        assert_eq!(parsed.link, None);
+    }
+
+    #[test]
+    fn parse_keeps_unmatched_lines_as_body() {
+        let parser = CommitParser::new(&default_templates()).unwrap();
+
+        let parsed = parser
+            .parse("🐛 Fix\n\nFirst paragraph\n\nSecond paragraph\n")
+            .unwrap();
+
+        assert_eq!(
+            parsed.body,
+            Some("First paragraph\n\nSecond paragraph".into())
+        );
+    }
+
+    #[test]
+    fn unmatched_keeps_subject_as_message_and_remaining_lines_as_body() {
+        let parsed = ParsedCommit::unmatched("Handle null token\n\nRoot cause\nover lines\n");
+
+        assert_eq!(parsed.template, "");
+        assert_eq!(parsed.message, Some("Handle null token".into()));
+        assert_eq!(parsed.body, Some("Root cause\nover lines".into()));
+    }
+
+    #[test]
+    fn new_with_duplicate_placeholders_is_valid() {
+        let templates = HashMap::from([("bad".into(), template("{message} {message}"))]);
+
+        // Duplicate placeholders are captured once rather than producing an invalid regex.
+        assert!(CommitParser::new(&templates).is_ok());
+    }
+}
@@ -1,10 +1,23 @@
+use std::collections::BTreeMap;
+
 use anyhow::Context;
-use regex::Regex;
+use regex::{Captures, Regex};
 
 use crate::utils::string::OptionStr;
 
 pub trait Templator {
     fn replace_or_remove(&self, target: &str, replace: Option<String>) -> anyhow::Result<String>;
+
+    /// Remove any placeholders left without a value along with their surrounding brackets.
+    fn remove_placeholders(&self) -> anyhow::Result<String>;
+
+    /// Replace placeholders with their values in a single pass so braces within a value are kept as is,
+    /// placeholders without a value are removed along with their surrounding brackets.
+    fn render(&self, values: &BTreeMap<String, Option<String>>) -> anyhow::Result<String>;
+}
+
+fn placeholder_regex() -> anyhow::Result<Regex> {
+    Ok(Regex::new(r"\{(\w+)\}")?)
 }
 
 fn brackets_regex(target: &str) -> anyhow::Result<Regex> {
@@ -38,12 +51,79 @@ impl Templator for String {
 
         Ok(message.trim().into())
     }
+
+    fn remove_placeholders(&self) -> anyhow::Result<String> {
+        let names = placeholder_regex()?
+            .captures_iter(self)
+            .map(|c| c[1].to_string())
+            .collect::<Vec<_>>();
+
+        names.iter().try_fold(self.clone(), |message, name| {
+            message.replace_or_remove(name, None)
+        })
+    }
+
+    fn render(&self, values: &BTreeMap<String, Option<String>>) -> anyhow::Result<String> {
+        let values = values
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str(), value.clone().none_if_empty()?)))
+            .collect::<BTreeMap<_, _>>();
+
+        let placeholder = placeholder_regex()?;
+
+        // Remove placeholders without a value from the template before any value is injected.
+        let template = placeholder
+            .captures_iter(self)
+            .map(|c| c[1].to_string())
+            .filter(|name| !values.contains_key(name.as_str()))
+            .collect::<Vec<_>>()
+            .iter()
+            .try_fold(self.clone(), |template, name| {
+                template.replace_or_remove(name, None)
+            })?;
+
+        let message = placeholder.replace_all(&template, |c: &Captures| match values.get(&c[1]) {
+            Some(value) => {
+                log::info!("replace '{}' from template with '{}'", &c[1], value);
+                value.to_string()
+            }
+            None => c[0].to_string(),
+        });
+
+        Ok(message.trim().into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn remove_placeholders_removes_any_remaining_placeholders() {
+        let message = "[{ticket_num}] Fix ({reviewer})".to_string();
+
+        assert_eq!("Fix", message.remove_placeholders().unwrap());
+    }
+
+    #[test]
+    fn render_keeps_braces_within_values() {
+        let values = BTreeMap::from([
+            (
+                "message".to_string(),
+                Some("Handle {body} in {config}".to_string()),
+            ),
+            ("body".to_string(), Some("body".to_string())),
+            ("scope".to_string(), None),
+        ]);
+
+        let message = "({scope}) {message}\n\n{body}\n\n{reviewer}".to_string();
+
+        assert_eq!(
+            "Handle {body} in {config}\n\nbody",
+            message.render(&values).unwrap()
+        );
+    }
+
     #[test]
     fn brackets_match() {
         let regex = brackets_regex("target").unwrap();
@@ -1,6 +1,8 @@
 use std::fmt::Debug;
 
 use crate::adapters::sqlite::Sqlite;
+#[cfg(feature = "native-git")]
+use crate::adapters::NativeGit;
 use crate::adapters::{Git, GitCommand};
 use crate::app_config::AppConfig;
 use crate::app_context::AppContext;
@@ -11,6 +13,12 @@ use clap::clap_derive::ArgEnum;
 use clap::Parser;
 use directories::ProjectDirs;
 
+/// Git implementation selected at build time via the `native-git` feature.
+#[cfg(not(feature = "native-git"))]
+pub type AppGit = Git<GitCommand>;
+#[cfg(feature = "native-git")]
+pub type AppGit = NativeGit<GitCommand>;
+
 #[derive(Clone, Debug, ArgEnum, Default, PartialEq, Eq)]
 pub enum Interactive {
     #[default]
@@ -43,10 +51,13 @@ pub struct Cli {
 
 impl Cli {
     // TODO: refactor to return Errors
-    pub fn init(&self) -> anyhow::Result<AppContext<Git<GitCommand>, Sqlite>> {
+    pub fn init(&self) -> anyhow::Result<AppContext<AppGit, Sqlite>> {
         self.log.init_logger();
 
+        #[cfg(not(feature = "native-git"))]
         let git = Git { git: GitCommand };
+        #[cfg(feature = "native-git")]
+        let git = NativeGit::open(Git { git: GitCommand })?;
 
         let mut connection = AppConfig::db_connection()?;
 
@@ -62,7 +73,7 @@ impl Cli {
                     default: config_dir.join("default.yml"),
                     conventional: config_dir.join("conventional.yml"),
                 }),
-                version: 4,
+                version: 9,
             },
         )?;
 
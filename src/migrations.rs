@@ -21,7 +21,7 @@ pub struct MigrationContext {
 pub fn db_migrations(
     connection: &mut Connection,
     context: MigrationContext,
-) -> anyhow::Result<Migrations> {
+) -> anyhow::Result<Migrations<'_>> {
     log::info!("Run migrations for version {}", context.version);
 
     // TODO: Move the migrations into a directory https://github.com/cljoly/rusqlite_migration/blob/08dc155cdedc83a2aef1017e95315fa6ca501daf/examples/from-directory/migrations/01-friend_car/up.sql#L1
@@ -47,6 +47,27 @@ pub fn db_migrations(
             .down("ALTER TABLE branch DROP COLUMN link;"),
         M::up("ALTER TABLE branch ADD COLUMN scope TEXT;")
             .down("ALTER TABLE branch DROP COLUMN scope;"),
+        M::up(
+            "CREATE TABLE IF NOT EXISTS history (
+                id TEXT NOT NULL PRIMARY KEY,
+                repo TEXT NOT NULL,
+                branch TEXT NOT NULL,
+                template TEXT NOT NULL,
+                inputs TEXT NOT NULL,
+                message TEXT NOT NULL,
+                status TEXT NOT NULL,
+                created TEXT NOT NULL
+            );",
+        )
+        .down("DROP TABLE history;"),
+        M::up("ALTER TABLE branch ADD COLUMN tickets TEXT;")
+            .down("ALTER TABLE branch DROP COLUMN tickets;"),
+        M::up("ALTER TABLE branch ADD COLUMN last_used TEXT;")
+            .down("ALTER TABLE branch DROP COLUMN last_used;"),
+        M::up("ALTER TABLE branch ADD COLUMN archived TEXT;")
+            .down("ALTER TABLE branch DROP COLUMN archived;"),
+        M::up("ALTER TABLE branch ADD COLUMN repo TEXT;")
+            .down("ALTER TABLE branch DROP COLUMN repo;"),
     ]);
 
     let current_version: usize = migrations
@@ -132,6 +153,7 @@ fn migrate_default_configuration(
 }
 
 #[cfg(test)]
+#[allow(clippy::get_first)]
 mod tests {
     use rusqlite::Connection;
     use std::path::Path;
@@ -322,6 +344,76 @@ mod tests {
         assert_eq!(conventional_config.2, "DISABLED");
     }
 
+    #[test]
+    fn verify_migration_5() {
+        let context = MigrationContext {
+            default_configs: None,
+            version: 5,
+        };
+        let (_, tables, _) = arrange(context);
+
+        assert_eq!(tables.len(), 3);
+        assert!(tables.contains(&"branch".to_string()));
+        assert!(tables.contains(&"config".to_string()));
+        assert!(tables.contains(&"history".to_string()));
+    }
+
+    #[test]
+    fn verify_migration_6() {
+        let context = MigrationContext {
+            default_configs: None,
+            version: 6,
+        };
+        let (connection, tables, _) = arrange(context);
+
+        assert_eq!(tables.len(), 3);
+
+        let statement = connection.prepare("SELECT tickets FROM branch").unwrap();
+        assert_eq!(statement.column_names(), vec!["tickets"]);
+    }
+
+    #[test]
+    fn verify_migration_7() {
+        let context = MigrationContext {
+            default_configs: None,
+            version: 7,
+        };
+        let (connection, tables, _) = arrange(context);
+
+        assert_eq!(tables.len(), 3);
+
+        let statement = connection.prepare("SELECT last_used FROM branch").unwrap();
+        assert_eq!(statement.column_names(), vec!["last_used"]);
+    }
+
+    #[test]
+    fn verify_migration_8() {
+        let context = MigrationContext {
+            default_configs: None,
+            version: 8,
+        };
+        let (connection, tables, _) = arrange(context);
+
+        assert_eq!(tables.len(), 3);
+
+        let statement = connection.prepare("SELECT archived FROM branch").unwrap();
+        assert_eq!(statement.column_names(), vec!["archived"]);
+    }
+
+    #[test]
+    fn verify_migration_9() {
+        let context = MigrationContext {
+            default_configs: None,
+            version: 9,
+        };
+        let (connection, tables, _) = arrange(context);
+
+        assert_eq!(tables.len(), 3);
+
+        let statement = connection.prepare("SELECT repo FROM branch").unwrap();
+        assert_eq!(statement.column_names(), vec!["repo"]);
+    }
+
     fn get_table_names(connection: &mut Connection) -> Vec<String> {
         let mut statement = connection
             .prepare("SELECT name FROM sqlite_schema WHERE type='table'")
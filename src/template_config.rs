@@ -5,35 +5,310 @@ use serde::{Deserialize, Serialize};
 use crate::{
     domain::{
         errors::{Errors, UserInputError},
-        models::path::AbsolutePath,
+        models::{path::AbsolutePath, Bump},
     },
-    utils::get_file_contents,
+    utils::{get_file_contents, glob::glob_regex},
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TemplateConfig {
     pub commit: CommitConfig,
+    #[serde(default)]
+    pub checkout: CheckoutConfig,
+    #[serde(default)]
+    pub finish: FinishConfig,
+    /// Message for merge commits created when finishing a branch.
+    #[serde(default = "MessageTemplate::default_merge")]
+    pub merge: MessageTemplate,
+    /// Message for the single commit created when squashing a branch.
+    #[serde(default = "MessageTemplate::default_squash")]
+    pub squash: MessageTemplate,
+    /// Message for annotated version tags.
+    #[serde(default = "MessageTemplate::default_tag")]
+    pub tag: MessageTemplate,
 }
 
+impl Default for TemplateConfig {
+    fn default() -> Self {
+        TemplateConfig {
+            commit: CommitConfig::default(),
+            checkout: CheckoutConfig::default(),
+            finish: FinishConfig::default(),
+            merge: MessageTemplate::default_merge(),
+            squash: MessageTemplate::default_squash(),
+            tag: MessageTemplate::default_tag(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutConfig {
+    /// Inherit ticket, scope & link from the current branch when creating a new branch.
+    #[serde(default)]
+    pub inherit: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FinishConfig {
+    /// Branch finished branches are merged into, defaults to the remote's default branch.
+    pub base: Option<String>,
+    /// Squash branches into a single commit rather than creating a merge commit.
+    #[serde(default)]
+    pub squash: bool,
+    /// Delete branches once merged.
+    #[serde(default)]
+    pub delete: bool,
+}
+
+/// Message rendered with the commit placeholders along with `{source_branch}`, `{target_branch}` & `{commits}`.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageTemplate {
+    pub content: String,
+}
+
+impl MessageTemplate {
+    fn default_merge() -> Self {
+        MessageTemplate {
+            content: "[{ticket_num}] Merge '{source_branch}' into {target_branch}\n\n{commits}"
+                .into(),
+        }
+    }
+
+    fn default_squash() -> Self {
+        MessageTemplate {
+            content: "[{ticket_num}] {source_branch}\n\n{commits}".into(),
+        }
+    }
+
+    fn default_tag() -> Self {
+        MessageTemplate {
+            content: "Release {version}\n\n{commits}".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CommitConfig {
     pub templates: HashMap<String, Template>,
+    /// Scopes offered as choices when prompting for a commit scope.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Path globs mapped to a scope used to suggest a scope from staged files.
+    #[serde(default)]
+    pub scope_paths: Vec<ScopePath>,
+    /// Path globs used to suggest a template when all staged files match.
+    #[serde(default)]
+    pub template_paths: Vec<TemplatePath>,
+    /// Section titles used when generating a changelog.
+    #[serde(default)]
+    pub changelog: ChangelogConfig,
+    /// Templates mapped to the version increment they warrant.
+    #[serde(default)]
+    pub bumps: BumpConfig,
+    /// Issue tracker url pattern i.e. `https://tracker/browse/{ticket_num}` used when a branch has no stored link.
+    pub ticket_link: Option<String>,
+    /// Separator joining every branch ticket into `{tickets}`, defaults to `, `.
+    pub ticket_separator: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ChangelogConfig {
+    /// Ordered sections, templates sharing a title are grouped into the same section.
+    #[serde(default)]
+    pub sections: Vec<ChangelogSection>,
+    /// Title for commits that don't match any template, these are omitted if not set.
+    pub other: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BumpConfig {
+    #[serde(default)]
+    pub major: Vec<String>,
+    #[serde(default)]
+    pub minor: Vec<String>,
+    #[serde(default)]
+    pub patch: Vec<String>,
+    /// Footer tokens that mark a commit as a breaking change regardless of template.
+    #[serde(default = "BumpConfig::default_breaking_tokens")]
+    pub breaking_tokens: Vec<String>,
+}
+
+impl BumpConfig {
+    fn default_breaking_tokens() -> Vec<String> {
+        vec!["BREAKING CHANGE".into(), "BREAKING-CHANGE".into()]
+    }
+
+    /// Most significant increment configured for a template.
+    pub fn template_bump(&self, template: &str) -> Option<Bump> {
+        [
+            (Bump::Major, &self.major),
+            (Bump::Minor, &self.minor),
+            (Bump::Patch, &self.patch),
+        ]
+        .into_iter()
+        .find(|(_, templates)| templates.iter().any(|t| t == template))
+        .map(|(bump, _)| bump)
+    }
+
+    pub fn is_breaking(&self, message: &str) -> bool {
+        message.lines().any(|line| {
+            self.breaking_tokens
+                .iter()
+                .any(|token| line.trim_start().starts_with(&format!("{token}:")))
+        })
+    }
+}
+
+impl Default for BumpConfig {
+    fn default() -> Self {
+        BumpConfig {
+            major: vec![],
+            minor: vec![],
+            patch: vec![],
+            breaking_tokens: BumpConfig::default_breaking_tokens(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChangelogSection {
+    /// Name of the template i.e. `feat`.
+    pub template: String,
+    /// Section title i.e. `Features`.
+    pub title: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScopePath {
+    /// Path glob relative to the repository root i.e. `crates/parser/**`.
+    pub glob: String,
+    pub scope: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TemplatePath {
+    /// Name of the template to suggest i.e. `docs`.
+    pub template: String,
+    /// Path globs relative to the repository root i.e. `**/*.md`.
+    pub globs: Vec<String>,
+}
+
+/// Scopes matched against staged file paths.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StagedScopes {
+    pub scopes: Vec<String>,
+    /// Every staged file matched a configured scope path.
+    pub all_matched: bool,
+}
+
+impl StagedScopes {
+    /// Only a single scope when every staged file maps to it.
+    pub fn single(&self) -> Option<String> {
+        match (self.all_matched, self.scopes.as_slice()) {
+            (true, [scope]) => Some(scope.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl CommitConfig {
+    pub fn staged_scopes(&self, files: &[String]) -> anyhow::Result<StagedScopes> {
+        let rules = self
+            .scope_paths
+            .iter()
+            .map(|rule| Ok((glob_regex(&rule.glob)?, &rule.scope)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut staged = StagedScopes {
+            scopes: vec![],
+            all_matched: !rules.is_empty(),
+        };
+
+        for file in files {
+            // First matching rule wins.
+            match rules.iter().find(|(regex, _)| regex.is_match(file)) {
+                Some((_, scope)) if !staged.scopes.contains(scope) => {
+                    staged.scopes.push(scope.to_string())
+                }
+                Some(_) => {}
+                None => staged.all_matched = false,
+            }
+        }
+
+        Ok(staged)
+    }
+
+    /// First template whose globs match every staged file.
+    pub fn suggested_template(&self, files: &[String]) -> anyhow::Result<Option<String>> {
+        if files.is_empty() {
+            return Ok(None);
+        }
+
+        for rule in &self.template_paths {
+            if !self.templates.contains_key(&rule.template) {
+                log::warn!(
+                    "skipping suggestion for unknown template '{}'",
+                    rule.template
+                );
+                continue;
+            }
+
+            let globs = rule
+                .globs
+                .iter()
+                .map(|glob| glob_regex(glob))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let all_match = files
+                .iter()
+                .all(|file| globs.iter().any(|regex| regex.is_match(file)));
+
+            if all_match {
+                log::info!("suggesting template '{}' from staged files", rule.template);
+                return Ok(Some(rule.template.clone()));
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Template {
     pub description: String,
     pub content: String,
+    /// Footers (i.e. `BREAKING CHANGE`) prompted in order and injected into `{footers}`.
+    #[serde(default)]
+    pub footers: Vec<Footer>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Footer {
+    /// Footer token such as `Co-authored-by` rendered as `{token}: {value}`.
+    pub token: String,
+    pub description: Option<String>,
+}
+
+impl Template {
+    pub fn contains_placeholder(&self, name: &str) -> bool {
+        self.content.contains(&format!("{{{name}}}"))
+    }
+
+    /// Emoji within the subject line identifying the type of change i.e. `🐛`.
+    pub fn icon(&self) -> Option<&str> {
+        self.content
+            .lines()
+            .next()?
+            .split_whitespace()
+            .find(|word| !word.contains('{') && !word.is_ascii())
+    }
 }
 
 impl TemplateConfig {
     pub fn new(config_path: &AbsolutePath) -> Result<Self, Errors> {
         let config_contents =
             get_file_contents(config_path).map_err(|e| Errors::Configuration {
-                message: format!(
-                    "Failed to read configuration at path '{}'",
-                    config_path.to_string()
-                ),
+                message: format!("Failed to read configuration at path '{}'", config_path),
                 source: e,
             })?;
 
@@ -41,7 +316,7 @@ impl TemplateConfig {
             Errors::Configuration {
                 message: format!(
                     "Failed to parse configuration from please ensure yaml is valid.\n{}",
-                    config_path.to_string()
+                    config_path
                 ),
                 source: e.into(),
             }
@@ -65,8 +340,10 @@ impl TemplateConfig {
 #[cfg(test)]
 mod tests {
     use crate::{
-        domain::errors::UserInputError,
-        template_config::{CommitConfig, Template, TemplateConfig},
+        domain::{errors::UserInputError, models::Bump, parser::CommitParser},
+        template_config::{
+            BumpConfig, CommitConfig, ScopePath, Template, TemplateConfig, TemplatePath,
+        },
     };
     use fake::{Fake, Faker};
     use std::collections::HashMap;
@@ -82,9 +359,12 @@ mod tests {
                     Template {
                         description: key.clone(),
                         content: key.clone(),
+                        footers: vec![],
                     },
                 )]),
+                ..CommitConfig::default()
             },
+            ..TemplateConfig::default()
         };
 
         let template_config = config.get_template_config(&key).unwrap();
@@ -92,6 +372,203 @@ mod tests {
         assert_eq!(key, template_config.description);
     }
 
+    #[test]
+    fn staged_scopes_with_all_files_matching_one_scope_is_single() {
+        let config = fake_scope_paths_config();
+        let files = [
+            "crates/parser/src/lib.rs".into(),
+            "crates/parser/Cargo.toml".into(),
+        ];
+
+        let staged = config.staged_scopes(&files).unwrap();
+
+        assert_eq!(vec!["parser".to_string()], staged.scopes);
+        assert_eq!(Some("parser".into()), staged.single());
+    }
+
+    #[test]
+    fn staged_scopes_with_files_matching_several_scopes_has_no_single() {
+        let config = fake_scope_paths_config();
+        let files = ["crates/parser/src/lib.rs".into(), "src/cli/mod.rs".into()];
+
+        let staged = config.staged_scopes(&files).unwrap();
+
+        assert_eq!(vec!["parser".to_string(), "cli".to_string()], staged.scopes);
+        assert_eq!(None, staged.single());
+    }
+
+    #[test]
+    fn staged_scopes_with_unmatched_file_has_no_single() {
+        let config = fake_scope_paths_config();
+        let files = ["crates/parser/src/lib.rs".into(), "README.md".into()];
+
+        let staged = config.staged_scopes(&files).unwrap();
+
+        assert_eq!(vec!["parser".to_string()], staged.scopes);
+        assert!(!staged.all_matched);
+        assert_eq!(None, staged.single());
+    }
+
+    #[test]
+    fn suggested_template_when_all_staged_files_match_a_rule() {
+        let config = fake_template_paths_config();
+        let files = ["README.md".into(), "docs/guide.md".into()];
+
+        let suggested = config.suggested_template(&files).unwrap();
+
+        assert_eq!(Some("docs".into()), suggested);
+    }
+
+    #[test]
+    fn suggested_template_is_none_when_staged_files_are_mixed() {
+        let config = fake_template_paths_config();
+        let files = ["README.md".into(), "tests/commit_test.rs".into()];
+
+        let suggested = config.suggested_template(&files).unwrap();
+
+        assert_eq!(None, suggested);
+    }
+
+    #[test]
+    fn suggested_template_skips_rules_for_unknown_templates() {
+        let mut config = fake_template_paths_config();
+        config.templates.remove("test");
+        let files = ["tests/commit_test.rs".into()];
+
+        let suggested = config.suggested_template(&files).unwrap();
+
+        assert_eq!(None, suggested);
+    }
+
+    #[test]
+    fn icon_is_taken_from_subject_line() {
+        let template = |content: &str| Template {
+            description: Faker.fake(),
+            content: content.into(),
+            footers: vec![],
+        };
+
+        assert_eq!(
+            Some("🐛"),
+            template("[{ticket_num}] 🐛 {message}\n\n✨ {body}").icon()
+        );
+        assert_eq!(None, template("fix({scope}): {message}").icon());
+    }
+
+    fn fake_template_paths_config() -> CommitConfig {
+        let template = Template {
+            description: Faker.fake(),
+            content: Faker.fake(),
+            footers: vec![],
+        };
+
+        CommitConfig {
+            templates: HashMap::from([
+                ("docs".into(), template.clone()),
+                ("test".into(), template),
+            ]),
+            template_paths: vec![
+                TemplatePath {
+                    template: "test".into(),
+                    globs: vec!["tests/**".into()],
+                },
+                TemplatePath {
+                    template: "docs".into(),
+                    globs: vec!["**/*.md".into()],
+                },
+            ],
+            ..CommitConfig::default()
+        }
+    }
+
+    fn fake_scope_paths_config() -> CommitConfig {
+        CommitConfig {
+            scope_paths: vec![
+                ScopePath {
+                    glob: "crates/parser/**".into(),
+                    scope: "parser".into(),
+                },
+                ScopePath {
+                    glob: "src/cli/**".into(),
+                    scope: "cli".into(),
+                },
+            ],
+            ..CommitConfig::default()
+        }
+    }
+
+    #[test]
+    fn template_bump_uses_most_significant_rule() {
+        let bumps = BumpConfig {
+            major: vec!["break".into()],
+            minor: vec!["feat".into(), "break".into()],
+            patch: vec!["fix".into()],
+            ..BumpConfig::default()
+        };
+
+        assert_eq!(Some(Bump::Major), bumps.template_bump("break"));
+        assert_eq!(Some(Bump::Minor), bumps.template_bump("feat"));
+        assert_eq!(Some(Bump::Patch), bumps.template_bump("fix"));
+        assert_eq!(None, bumps.template_bump("chore"));
+    }
+
+    #[test]
+    fn is_breaking_with_breaking_token_footer() {
+        let bumps = BumpConfig::default();
+
+        assert!(bumps.is_breaking("feat: Add\n\nBREAKING CHANGE: removed flag"));
+        assert!(bumps.is_breaking("feat: Add\n\nBREAKING-CHANGE: removed flag"));
+        assert!(!bumps.is_breaking("feat: Add BREAKING CHANGE"));
+    }
+
+    #[test]
+    fn shipped_configs_are_valid() {
+        let root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+        for file in ["templates/default.yml", "templates/conventional.yml"] {
+            let path = root.join(file).try_into().unwrap();
+            let config = TemplateConfig::new(&path).unwrap();
+
+            for rule in config.commit.template_paths {
+                assert!(config.commit.templates.contains_key(&rule.template));
+            }
+
+            for section in config.commit.changelog.sections {
+                assert!(config.commit.templates.contains_key(&section.template));
+            }
+
+            let bumps = &config.commit.bumps;
+            for template in bumps.major.iter().chain(&bumps.minor).chain(&bumps.patch) {
+                assert!(config.commit.templates.contains_key(template));
+            }
+
+            assert!(CommitParser::new(&config.commit.templates).is_ok());
+            assert!(config.merge.content.contains("{commits}"));
+            assert!(config.squash.content.contains("{commits}"));
+            assert!(config.tag.content.contains("{version}"));
+        }
+    }
+
+    #[test]
+    fn shipped_template_contents_do_not_include_yaml_comments() {
+        let root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+        for file in ["templates/default.yml", "templates/conventional.yml"] {
+            let path = root.join(file).try_into().unwrap();
+            let config = TemplateConfig::new(&path).unwrap();
+
+            let contents = config.commit.templates.values().map(|t| &t.content).chain([
+                &config.merge.content,
+                &config.squash.content,
+                &config.tag.content,
+            ]);
+
+            for content in contents {
+                assert!(!content.contains('#'), "{file}: {content:?}");
+            }
+        }
+    }
+
     #[test]
     fn get_template_config_errors_on_non_existent_key() {
         let key: String = Faker.fake();
@@ -99,7 +576,9 @@ mod tests {
         let config = TemplateConfig {
             commit: CommitConfig {
                 templates: HashMap::from([]),
+                ..CommitConfig::default()
             },
+            ..TemplateConfig::default()
         };
 
         let result = config.get_template_config(&key).unwrap_err();
@@ -0,0 +1,26 @@
+use std::process::Command;
+
+use anyhow::Context;
+
+/// Open a url using the platform's default handler.
+pub fn open(url: &str) -> anyhow::Result<()> {
+    let mut command = match std::env::consts::OS {
+        "macos" => Command::new("open"),
+        // Avoids 'cmd /C start' which would interpret characters like '&' within the url.
+        "windows" => {
+            let mut command = Command::new("rundll32");
+            command.arg("url.dll,FileProtocolHandler");
+            command
+        }
+        _ => Command::new("xdg-open"),
+    };
+
+    let status = command
+        .arg(url)
+        .status()
+        .with_context(|| format!("Failed to launch browser for '{url}'"))?;
+
+    anyhow::ensure!(status.success(), "Browser exited with {status}");
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use regex::Regex;
+
+/// Convert a path glob (i.e. `crates/parser/**`) into an anchored regex.
+/// `**` matches across directories, `*` & `?` match within a single path segment.
+pub fn glob_regex(glob: &str) -> anyhow::Result<Regex> {
+    let mut pattern = String::from("^");
+    let mut chars = glob.trim().chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // Allow '**/' to also match zero directories.
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    pattern.push_str("(.*/)?");
+                } else {
+                    pattern.push_str(".*");
+                }
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    pattern.push('$');
+
+    Ok(Regex::new(&pattern)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_star_matches_nested_paths() {
+        let regex = glob_regex("crates/parser/**").unwrap();
+        assert!(regex.is_match("crates/parser/src/lib.rs"));
+        assert!(regex.is_match("crates/parser/Cargo.toml"));
+        assert!(!regex.is_match("crates/lexer/src/lib.rs"));
+    }
+
+    #[test]
+    fn single_star_does_not_cross_directories() {
+        let regex = glob_regex("src/*.rs").unwrap();
+        assert!(regex.is_match("src/lib.rs"));
+        assert!(!regex.is_match("src/cli/mod.rs"));
+    }
+
+    #[test]
+    fn leading_double_star_matches_any_directory_depth() {
+        let regex = glob_regex("**/*.md").unwrap();
+        assert!(regex.is_match("README.md"));
+        assert!(regex.is_match("docs/guide/intro.md"));
+        assert!(!regex.is_match("src/main.rs"));
+    }
+
+    #[test]
+    fn special_characters_are_escaped() {
+        let regex = glob_regex("file.(rs)").unwrap();
+        assert!(regex.is_match("file.(rs)"));
+        assert!(!regex.is_match("fileX(rs)"));
+    }
+}
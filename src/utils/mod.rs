@@ -1,4 +1,6 @@
+pub mod browser;
 pub mod file;
+pub mod glob;
 pub mod or_else_try;
 pub mod string;
 
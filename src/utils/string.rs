@@ -8,6 +8,29 @@ pub fn into_option<S: Into<String>>(value: S) -> Option<String> {
     }
 }
 
+/// Split a comma separated value into its trimmed non-empty items.
+pub fn split_list(value: &str) -> Vec<String> {
+    value.split(',').filter_map(into_option).collect()
+}
+
+/// Split whitespace or newline separated links, commas are valid within urls so aren't separators.
+pub fn split_links(value: &str) -> Vec<String> {
+    value.split_whitespace().map(String::from).collect()
+}
+
+/// Percent-encode all but unreserved url characters so a value can't alter the url it's placed in.
+pub fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
 pub trait OptionStr<T> {
     fn none_if_empty(self) -> Option<T>;
 }
@@ -35,6 +58,21 @@ impl OptionStr<String> for String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn split_list_trims_and_skips_empty_items() {
+        assert_eq!(vec!["ABC-1", "ABC-2"], split_list(" ABC-1, ,ABC-2 ,"));
+        assert!(split_list("").is_empty());
+    }
+
+    #[test]
+    fn split_links_splits_on_whitespace_only() {
+        assert_eq!(
+            vec!["https://a/1,2", "https://b/3", "https://c/4"],
+            split_links(" https://a/1,2  https://b/3\nhttps://c/4\n")
+        );
+        assert!(split_links("").is_empty());
+    }
+
     #[test]
     fn empty_strings_wrapped_in_some_should_be_mapped_to_none() {
         for item in ["", " ", "\t", "\n"] {
@@ -55,4 +93,10 @@ mod tests {
             assert_eq!(item.trim(), option.unwrap());
         }
     }
+
+    #[test]
+    fn percent_encode_escapes_reserved_characters() {
+        assert_eq!("ABC-1.2_x~", percent_encode("ABC-1.2_x~"));
+        assert_eq!("A%26B%7C%5E%20%2F%C3%A9", percent_encode("A&B|^ /é"));
+    }
 }
@@ -0,0 +1,319 @@
+mod fakers;
+mod repos;
+
+use std::collections::HashMap;
+
+use crate::{
+    fakers::{fake_commit, fake_config, fake_context, fake_template, GitCommandMock},
+    repos::{git, git_in, init_repo, temp_dir},
+};
+use git_kit::{
+    adapters::sqlite::Sqlite,
+    domain::{
+        adapters::{Git, Store},
+        commands::apply_ticket::{handler, TicketChange},
+        errors::{Errors, GitError},
+        models::Branch,
+    },
+    template_config::CommitConfig,
+};
+use rusqlite::Connection;
+
+#[test]
+fn apply_ticket_rewrites_commits_missing_a_ticket() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        merge_base: |base| {
+            assert_eq!("main", base);
+            Ok("0000000base".into())
+        },
+        log: |range| {
+            assert_eq!("0000000base..HEAD", range);
+            Ok(vec![
+                fake_commit("3333333ccc", "[ABC-1] ✨ Add flag"),
+                fake_commit("2222222bbb", "🐛 Fix crash\n\nSome details"),
+                fake_commit("1111111aaa", "✨ Add docs"),
+                fake_commit("0000000fff", "Unrelated message"),
+            ])
+        },
+        reword: |messages| {
+            assert_eq!(
+                [
+                    (
+                        "2222222bbb".to_string(),
+                        "[ABC-1] 🐛 Fix crash\n\nSome details".to_string()
+                    ),
+                    ("1111111aaa".to_string(), "[ABC-1] ✨ Add docs".to_string()),
+                ],
+                messages
+            );
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+    persist_branch(&context.git, &context.store)?;
+
+    // Act
+    let changes = handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "main",
+        false,
+    )?;
+
+    // Assert
+    assert_eq!(
+        vec![
+            TicketChange {
+                hash: "2222222bbb".into(),
+                before: "🐛 Fix crash\n\nSome details".into(),
+                after: "[ABC-1] 🐛 Fix crash\n\nSome details".into(),
+            },
+            TicketChange {
+                hash: "1111111aaa".into(),
+                before: "✨ Add docs".into(),
+                after: "[ABC-1] ✨ Add docs".into(),
+            },
+        ],
+        changes
+    );
+
+    Ok(())
+}
+
+#[test]
+fn apply_ticket_dry_run_does_not_rewrite_history() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        merge_base: |_| Ok("0000000base".into()),
+        log: |_| Ok(vec![fake_commit("1111111aaa", "✨ Add docs")]),
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+    persist_branch(&context.git, &context.store)?;
+
+    // Act
+    let changes = handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "main",
+        true,
+    )?;
+
+    // Assert
+    assert_eq!(1, changes.len());
+    assert_eq!("[ABC-1] ✨ Add docs", changes[0].after);
+
+    Ok(())
+}
+
+#[test]
+fn apply_ticket_without_branch_context_errors() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+
+    // Act
+    let result = handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "main",
+        false,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn apply_ticket_with_failing_store_propagates_error() -> anyhow::Result<()> {
+    // Arrange
+    let store = Sqlite::new(Connection::open_in_memory()?);
+
+    // Act
+    let result = handler(
+        &GitCommandMock::fake(),
+        &store,
+        &fake_commit_config(),
+        "main",
+        false,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::PersistError(_))));
+
+    Ok(())
+}
+
+#[test]
+fn apply_ticket_with_detached_head_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        is_detached: || Ok(true),
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+
+    // Act
+    let result = handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "main",
+        false,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::Git(GitError::DetachedHead))));
+
+    Ok(())
+}
+
+#[test]
+fn apply_ticket_across_merge_commit_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        merge_base: |_| Ok("0000000base".into()),
+        log: |_| Ok(vec![fake_commit("1111111aaa", "✨ Add docs")]),
+        merges: |range| {
+            assert_eq!("1111111aaa..HEAD", range);
+            Ok(vec!["2222222bbb".into()])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+    persist_branch(&context.git, &context.store)?;
+
+    // Act
+    let result = handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "main",
+        false,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn apply_ticket_rewrites_commits_onto_the_same_parent() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("apply-ticket-linear")?;
+    let repo = init_repo(&root.join("project"))?;
+    git(&repo, &["checkout", "--quiet", "-b", "feature"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "✨ First fix"],
+    )?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "🐛 Second fix"],
+    )?;
+
+    let context = fake_context(git_in(&repo), fake_config())?;
+    persist_branch(&context.git, &context.store)?;
+
+    // Act
+    handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "main",
+        false,
+    )?;
+
+    // Assert
+    assert_eq!(
+        "[ABC-1] 🐛 Second fix\n[ABC-1] ✨ First fix",
+        git(&repo, &["log", "--format=%s", "main..HEAD"])?
+    );
+    assert_eq!(
+        git(&repo, &["rev-parse", "main"])?,
+        git(&repo, &["rev-parse", "HEAD~2"])?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn apply_ticket_across_merge_commit_keeps_history() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("apply-ticket-merge")?;
+    let repo = init_repo(&root.join("project"))?;
+    git(&repo, &["tag", "base"])?;
+    git(&repo, &["checkout", "--quiet", "-b", "feature"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "✨ First fix"],
+    )?;
+    git(&repo, &["checkout", "--quiet", "main"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "Main work"],
+    )?;
+    git(&repo, &["checkout", "--quiet", "feature"])?;
+    git(&repo, &["merge", "--quiet", "--no-edit", "main"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "🐛 Second fix"],
+    )?;
+    let head = git(&repo, &["rev-parse", "HEAD"])?;
+
+    let context = fake_context(git_in(&repo), fake_config())?;
+    persist_branch(&context.git, &context.store)?;
+
+    // Act
+    let result = handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "base",
+        false,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+    assert_eq!(head, git(&repo, &["rev-parse", "HEAD"])?);
+    assert_eq!(
+        "Merge branch 'main' into feature",
+        git(&repo, &["log", "--merges", "--format=%s", "base..HEAD"])?
+    );
+
+    Ok(())
+}
+
+fn persist_branch<G: Git, S: Store>(git: &G, store: &S) -> anyhow::Result<()> {
+    let branch = Branch::new(
+        &git.branch_name()?,
+        &git.repository_name()?,
+        Some("ABC-1".into()),
+        None,
+        None,
+    );
+    store.persist_branch(&branch)?;
+
+    Ok(())
+}
+
+fn fake_commit_config() -> CommitConfig {
+    CommitConfig {
+        templates: HashMap::from([
+            ("feat".into(), fake_template("[{ticket_num}] ✨ {message}")),
+            ("bug".into(), fake_template("[{ticket_num}] 🐛 {message}")),
+        ]),
+        ..CommitConfig::default()
+    }
+}
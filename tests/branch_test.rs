@@ -0,0 +1,237 @@
+mod fakers;
+
+use crate::fakers::{fake_config, fake_context, GitCommandMock};
+use git_kit::domain::{
+    adapters::{Git, Store},
+    commands::branch::{attach, orphans, rename},
+    errors::{Errors, GitError},
+    models::Branch,
+};
+
+#[test]
+fn rename_moves_stored_context_to_new_branch() -> anyhow::Result<()> {
+    // Arrange
+    let mut context = fake_context(GitCommandMock::fake(), fake_config())?;
+    let repo = context.git.repository_name()?;
+    let branch = Branch::new("feat/old", &repo, Some("ABC-1".into()), None, None);
+    context.store.persist_branch(&branch)?;
+
+    // Act
+    let moved = rename(&context.git, &mut context.store, "feat/old", "feat/new")?;
+
+    // Assert
+    assert_eq!(moved.map(|b| b.ticket), Some("ABC-1".into()));
+    assert!(context.store.get_branch("feat/old", &repo).is_err());
+    assert_eq!(context.store.get_branch("feat/new", &repo)?.ticket, "ABC-1");
+
+    Ok(())
+}
+
+#[test]
+fn rename_without_stored_context_only_renames_branch() -> anyhow::Result<()> {
+    // Arrange
+    let mut context = fake_context(GitCommandMock::fake(), fake_config())?;
+
+    // Act
+    let moved = rename(&context.git, &mut context.store, "feat/old", "feat/new")?;
+
+    // Assert
+    assert!(moved.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn rename_on_git_failure_keeps_stored_context() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        rename_branch: |from, to| {
+            Err(GitError::Rename {
+                from: from.into(),
+                to: to.into(),
+            })
+        },
+        ..GitCommandMock::fake()
+    };
+    let mut context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    let branch = Branch::new("feat/old", &repo, Some("ABC-1".into()), None, None);
+    context.store.persist_branch(&branch)?;
+
+    // Act
+    let result = rename(&context.git, &mut context.store, "feat/old", "feat/new");
+
+    // Assert
+    assert!(matches!(result, Err(Errors::Git(GitError::Rename { .. }))));
+    assert_eq!(branch, context.store.get_branch("feat/old", &repo)?);
+
+    Ok(())
+}
+
+#[test]
+fn rename_errors_when_new_branch_has_stored_context() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        rename_branch: |_, _| panic!("branch should not be renamed"),
+        ..GitCommandMock::fake()
+    };
+    let mut context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    context
+        .store
+        .persist_branch(&Branch::new("feat/new", &repo, None, None, None))?;
+
+    // Act
+    let result = rename(&context.git, &mut context.store, "feat/old", "feat/new");
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn orphans_excludes_archived_branches() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branches: || Ok(vec!["main".into()]),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    context
+        .store
+        .persist_branch(&Branch::new("feat/done", &repo, None, None, None))?;
+    context.store.archive_branch("feat/done", &repo)?;
+
+    // Act
+    let orphans = orphans(&context.git, &context.store)?;
+
+    // Assert
+    assert!(orphans.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn orphans_excludes_sibling_repo_branches() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branches: || Ok(vec!["main".into()]),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    let sibling = format!("{repo}-gateway");
+    context
+        .store
+        .persist_branch(&Branch::new("feat", &sibling, None, None, None))?;
+    context.store.persist_branch(&Branch {
+        repo: None,
+        ..Branch::new("legacy", &sibling, None, None, None)
+    })?;
+
+    // Act
+    let orphans = orphans(&context.git, &context.store)?;
+
+    // Assert
+    assert!(orphans.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn orphans_detects_renamed_branches_from_reflog() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branches: || Ok(vec!["main".into(), "feat/new".into(), "feat/kept".into()]),
+        branch_renames: || Ok(vec![("feat/old".into(), "feat/new".into())]),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+
+    for name in ["feat/old", "feat/kept", "feat/deleted"] {
+        context
+            .store
+            .persist_branch(&Branch::new(name, &repo, None, None, None))?;
+    }
+
+    // Act
+    let mut orphans = orphans(&context.git, &context.store)?;
+    orphans.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // Assert
+    let actual: Vec<(String, Option<String>)> = orphans
+        .into_iter()
+        .map(|orphan| (orphan.name, orphan.renamed))
+        .collect();
+
+    assert_eq!(
+        actual,
+        [
+            ("feat/deleted".to_string(), None),
+            ("feat/old".to_string(), Some("feat/new".to_string()))
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn orphans_offers_legacy_contexts_renamed_in_reflog() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branches: || Ok(vec!["main".into(), "feat/new".into()]),
+        branch_renames: || Ok(vec![("feat/old".into(), "feat/new".into())]),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+
+    for name in ["feat/old", "feat/deleted"] {
+        context.store.persist_branch(&Branch {
+            repo: None,
+            ..Branch::new(name, &repo, None, None, None)
+        })?;
+    }
+
+    // Act
+    let orphans = orphans(&context.git, &context.store)?;
+
+    // Assert
+    let actual: Vec<(String, Option<String>)> = orphans
+        .into_iter()
+        .map(|orphan| (orphan.name, orphan.renamed))
+        .collect();
+
+    assert_eq!(
+        actual,
+        [("feat/old".to_string(), Some("feat/new".to_string()))]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn attach_moves_orphaned_context_to_renamed_branch() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        rename_branch: |_, _| panic!("git branch should already be renamed"),
+        ..GitCommandMock::fake()
+    };
+    let mut context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    context
+        .store
+        .persist_branch(&Branch::new("feat/old", &repo, None, None, None))?;
+
+    // Act
+    let branch = attach(&context.git, &mut context.store, "feat/old", "feat/new")?;
+
+    // Assert
+    assert_eq!(branch.ticket, "feat/new");
+    assert!(context.store.get_branch("feat/old", &repo).is_err());
+
+    Ok(())
+}
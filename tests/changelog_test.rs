@@ -0,0 +1,141 @@
+mod fakers;
+
+use std::collections::HashMap;
+
+use crate::fakers::{fake_commit, fake_config, fake_context, fake_template, GitCommandMock};
+use git_kit::{
+    domain::{
+        adapters::{Git, Store},
+        commands::changelog::handler,
+        models::Branch,
+    },
+    template_config::{ChangelogConfig, ChangelogSection, CommitConfig},
+};
+
+#[test]
+fn changelog_groups_commits_by_configured_sections_and_scope() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        log: |range| {
+            assert_eq!("v1.0.0..HEAD", range);
+            Ok(vec![
+                fake_commit("1111111aaa", "feat(cli): Add flag\n\nIssue: ABC-1"),
+                fake_commit("2222222bbb", "fix: Fix crash"),
+                fake_commit("3333333ccc", "feat: Add command"),
+                fake_commit("4444444ddd", "chore: Tidy up"),
+                fake_commit("5555555eee", "Unrelated message"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+
+    let branch = Branch::new(
+        &context.git.branch_name()?,
+        &context.git.repository_name()?,
+        Some("ABC-1".into()),
+        Some("https://issues/ABC-1".into()),
+        None,
+    );
+    context.store.persist_branch(&branch)?;
+
+    let config = CommitConfig {
+        templates: HashMap::from([
+            (
+                "feat".into(),
+                fake_template("feat({scope}): {message}\n\nIssue: {ticket_num} {link}"),
+            ),
+            ("fix".into(), fake_template("fix({scope}): {message}")),
+            ("chore".into(), fake_template("chore({scope}): {message}")),
+        ]),
+        changelog: ChangelogConfig {
+            sections: vec![
+                ChangelogSection {
+                    template: "feat".into(),
+                    title: "Features".into(),
+                },
+                ChangelogSection {
+                    template: "fix".into(),
+                    title: "Fixes".into(),
+                },
+            ],
+            other: None,
+        },
+        ..CommitConfig::default()
+    };
+
+    // Act
+    let changelog = handler(&context.git, &context.store, &config, "v1.0.0..HEAD")?;
+
+    // Assert
+    let titles = changelog
+        .sections
+        .iter()
+        .map(|s| s.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["Features", "Fixes"], titles);
+
+    let features = &changelog.sections[0];
+    assert_eq!(None, features.scopes[0].scope);
+    assert_eq!("Add command", features.scopes[0].entries[0].message);
+    assert_eq!(Some("cli".into()), features.scopes[1].scope);
+
+    let flag = &features.scopes[1].entries[0];
+    assert_eq!("Add flag", flag.message);
+    assert_eq!(Some("ABC-1".into()), flag.ticket);
+    assert_eq!(Some("https://issues/ABC-1".into()), flag.link);
+
+    assert_eq!(
+        "Fix crash",
+        changelog.sections[1].scopes[0].entries[0].message
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn changelog_without_sections_uses_template_names_and_other() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        log: |_| {
+            Ok(vec![
+                fake_commit("1111111aaa", "fix: Fix crash"),
+                fake_commit("2222222bbb", "Unrelated message"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+
+    let config = CommitConfig {
+        templates: HashMap::from([("fix".into(), fake_template("fix({scope}): {message}"))]),
+        changelog: ChangelogConfig {
+            sections: vec![],
+            other: Some("Other".into()),
+        },
+        ..CommitConfig::default()
+    };
+
+    // Act
+    let changelog = handler(&context.git, &context.store, &config, "HEAD")?;
+
+    // Assert
+    let titles = changelog
+        .sections
+        .iter()
+        .map(|s| s.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["fix", "Other"], titles);
+
+    let other = &changelog.sections[1].scopes[0].entries[0];
+    assert_eq!("Unrelated message", other.message);
+    assert_eq!(None, other.template);
+
+    context.close()?;
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 mod fakers;
 
+use std::collections::BTreeMap;
+
 use fake::{Fake, Faker};
 use git_kit::domain::{
-    adapters::{CheckoutStatus, Store},
-    commands::checkout::{handler, Checkout},
-    errors::{GitError, PersistError},
+    adapters::{BranchLocation, CheckoutStatus, Git, Store},
+    commands::checkout::{handler, parent_context, status, Checkout},
+    errors::{Errors, GitError, PersistError},
     models::Branch,
 };
 
@@ -16,7 +18,7 @@ fn checkout_success_with_ticket() -> anyhow::Result<()> {
     let repo = Faker.fake::<String>();
 
     let command = Checkout {
-        ticket: Some(Faker.fake()),
+        tickets: vec![Faker.fake()],
         ..fake_checkout_args()
     };
 
@@ -29,7 +31,7 @@ fn checkout_success_with_ticket() -> anyhow::Result<()> {
     let context = fake_context(git_commands.clone(), fake_config())?;
 
     // Act
-    handler(&context.git, &context.store, command.clone())?;
+    checkout(&context.git, &context.store, command.clone())?;
 
     // Assert
     let branch = context.store.get_branch(&command.name, &repo)?;
@@ -41,7 +43,8 @@ fn checkout_success_with_ticket() -> anyhow::Result<()> {
 
     let expected = Branch {
         name,
-        ticket: command.ticket.unwrap(),
+        repo: Some(repo),
+        ticket: command.tickets[0].clone(),
         ..branch.clone()
     };
 
@@ -53,7 +56,7 @@ fn checkout_success_with_ticket() -> anyhow::Result<()> {
 }
 
 #[test]
-fn checkout_with_branch_already_exists_does_not_error() -> anyhow::Result<()> {
+fn checkout_with_new_branch_creates_from_head() -> anyhow::Result<()> {
     // Arrange
     let repo = Faker.fake::<String>();
 
@@ -63,7 +66,7 @@ fn checkout_with_branch_already_exists_does_not_error() -> anyhow::Result<()> {
         repo: Ok(repo.clone()),
         branch_name: Ok(command.name.clone()),
         checkout_res: |_, status| {
-            assert_eq!(status, CheckoutStatus::New);
+            assert_eq!(status, CheckoutStatus::New { from: None });
             Ok(())
         },
         ..GitCommandMock::fake()
@@ -72,7 +75,7 @@ fn checkout_with_branch_already_exists_does_not_error() -> anyhow::Result<()> {
     let context = fake_context(git_commands.clone(), fake_config())?;
 
     // Act
-    handler(&context.git, &context.store, command.clone())?;
+    checkout(&context.git, &context.store, command.clone())?;
 
     // Assert
     let branch = context.store.get_branch(&command.name, &repo)?;
@@ -84,11 +87,15 @@ fn checkout_with_branch_already_exists_does_not_error() -> anyhow::Result<()> {
 
     let expected = Branch {
         name,
-        ticket: command.ticket.unwrap(),
-        link: command.link,
+        repo: Some(repo),
+        ticket: command.tickets[0].clone(),
+        link: command.links.first().cloned(),
         scope: command.scope,
         created: branch.created,
-        data: None,
+        data: BTreeMap::new(),
+        tickets: vec![],
+        last_used: branch.last_used,
+        archived: None,
     };
 
     assert_eq!(branch, expected);
@@ -112,14 +119,32 @@ fn checkout_on_fail_to_checkout_branch_nothing_is_persisted() {
                 message: "failed to create or checkout existing branch!".into(),
             })
         },
+        branch_location: |_| Ok(None),
         commit_res: |_, _| panic!("commit method should not be called"),
         template_file_path: || panic!("template should not be needed"),
+        staged_files: || panic!("staged files should not be needed"),
+        operation_in_progress: || panic!("operation in progress should not be needed"),
+        is_detached: || panic!("detached should not be needed"),
+        log: |_| panic!("log should not be needed"),
+        is_ancestor: |_| panic!("is ancestor should not be needed"),
+        merges: |_| panic!("merges should not be needed"),
+        show: |_| panic!("show should not be needed"),
+        amend_message: |_| panic!("amend should not be needed"),
+        reword: |_| panic!("reword should not be needed"),
+        merge_base: |_| panic!("merge base should not be needed"),
+        tags: || panic!("tags should not be needed"),
+        create_tag: |_, _| panic!("create tag should not be needed"),
+        rename_branch: |_, _| panic!("rename branch should not be needed"),
+        branches: || panic!("branches should not be needed"),
+        branch_renames: || panic!("branch renames should not be needed"),
+        merge: |_, _, _| panic!("merge should not be needed"),
+        delete_branch: |_, _| panic!("delete branch should not be needed"),
     };
 
     let context = fake_context(git_commands.clone(), fake_config()).unwrap();
 
     // Act
-    handler(&context.git, &context.store, command.clone()).unwrap_err();
+    checkout(&context.git, &context.store, command.clone()).unwrap_err();
 
     // Assert
     let error = context
@@ -141,7 +166,7 @@ fn checkout_success_without_ticket_uses_branch_name() -> anyhow::Result<()> {
     let repo = Faker.fake::<String>();
 
     let command = Checkout {
-        ticket: None,
+        tickets: vec![],
         ..fake_checkout_args()
     };
 
@@ -154,7 +179,7 @@ fn checkout_success_without_ticket_uses_branch_name() -> anyhow::Result<()> {
     let context = fake_context(git_commands.clone(), fake_config())?;
 
     // Act
-    handler(&context.git, &context.store, command.clone())?;
+    checkout(&context.git, &context.store, command.clone())?;
 
     // Assert
     let branch = context.store.get_branch(&command.name, &repo)?;
@@ -166,11 +191,15 @@ fn checkout_success_without_ticket_uses_branch_name() -> anyhow::Result<()> {
 
     let expected = Branch {
         name,
+        repo: Some(repo),
         ticket: command.name,
         scope: command.scope,
-        link: command.link,
-        data: None,
+        link: command.links.first().cloned(),
+        data: BTreeMap::new(),
         created: branch.created,
+        tickets: vec![],
+        last_used: branch.last_used,
+        archived: None,
     };
 
     assert_eq!(branch, expected);
@@ -180,11 +209,267 @@ fn checkout_success_without_ticket_uses_branch_name() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn checkout_new_branch_inherits_parent_context() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock::fake();
+    let context = fake_context(git_commands.clone(), fake_config())?;
+    let repo = git_commands.repo.clone().unwrap();
+
+    let parent = Branch::with_tickets(
+        &git_commands.branch_name.clone().unwrap(),
+        &repo,
+        vec!["ABC-1".into(), "ABC-2".into()],
+        vec!["https://issues/ABC-1".into()],
+        Some("parser".into()),
+    );
+    context.store.persist_branch(&parent)?;
+
+    let command = Checkout {
+        tickets: vec![],
+        links: vec![],
+        scope: Some("cli".into()),
+        parent: parent_context(&context.git, &context.store),
+        ..fake_checkout_args()
+    };
+
+    // Act
+    let branch = checkout(&context.git, &context.store, command)?;
+
+    // Assert
+    assert_eq!("ABC-1", branch.ticket);
+    assert_eq!(Some("https://issues/ABC-1".into()), branch.link);
+    assert_eq!("ABC-2", branch.tickets[0].ticket);
+    assert_eq!(Some("cli".into()), branch.scope);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn checkout_existing_branch_does_not_inherit_parent_context() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        branch_location: |_| Ok(Some(BranchLocation::Local)),
+        checkout_res: |_, status| {
+            assert_eq!(status, CheckoutStatus::Existing);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git_commands.clone(), fake_config())?;
+
+    let parent = Branch::new(
+        &git_commands.branch_name.clone().unwrap(),
+        &git_commands.repo.clone().unwrap(),
+        Some("ABC-1".into()),
+        None,
+        Some("parser".into()),
+    );
+    context.store.persist_branch(&parent)?;
+
+    let command = Checkout {
+        tickets: vec![],
+        links: vec![],
+        scope: None,
+        parent: parent_context(&context.git, &context.store),
+        ..fake_checkout_args()
+    };
+
+    // Act
+    let branch = checkout(&context.git, &context.store, command.clone())?;
+
+    // Assert
+    assert_eq!(command.name, branch.ticket);
+    assert_eq!(None, branch.scope);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn checkout_new_branch_from_start_point() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        checkout_res: |_, status| {
+            assert_eq!(
+                status,
+                CheckoutStatus::New {
+                    from: Some("main".into())
+                }
+            );
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git_commands, fake_config())?;
+
+    let command = Checkout {
+        from: Some("main".into()),
+        ..fake_checkout_args()
+    };
+
+    // Act
+    checkout(&context.git, &context.store, command)?;
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn checkout_remote_branch_creates_tracking_branch() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        branch_location: |name| {
+            Ok(Some(BranchLocation::Remote {
+                upstream: format!("origin/{name}"),
+            }))
+        },
+        checkout_res: |name, status| {
+            assert_eq!(
+                status,
+                CheckoutStatus::Track {
+                    upstream: format!("origin/{name}")
+                }
+            );
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git_commands, fake_config())?;
+
+    // Act
+    checkout(&context.git, &context.store, fake_checkout_args())?;
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn checkout_existing_branch_with_start_point_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        branch_location: |_| Ok(Some(BranchLocation::Local)),
+        checkout_res: |_, _| panic!("checkout should not be called"),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git_commands, fake_config())?;
+
+    let command = Checkout {
+        from: Some("main".into()),
+        ..fake_checkout_args()
+    };
+
+    // Act
+    let error = checkout(&context.git, &context.store, command.clone()).unwrap_err();
+
+    // Assert
+    let expected = format!(
+        "Branch '{}' already exists, '--from' & '--track' only apply when creating a branch",
+        command.name
+    );
+    assert!(matches!(error, Errors::ValidationError { message } if message == expected));
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn checkout_track_without_remote_branch_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        checkout_res: |_, _| panic!("checkout should not be called"),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git_commands, fake_config())?;
+
+    let command = Checkout {
+        track: true,
+        ..fake_checkout_args()
+    };
+
+    // Act
+    let error = checkout(&context.git, &context.store, command.clone()).unwrap_err();
+
+    // Assert
+    let expected = format!("No remote branch '{}' found to track", command.name);
+    assert!(matches!(error, Errors::ValidationError { message } if message == expected));
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn checkout_failure_is_not_retried_as_existing_branch() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        checkout_res: |_, status| match status {
+            CheckoutStatus::New { .. } => Err(GitError::Validation {
+                message: "invalid reference: main".into(),
+            }),
+            _ => panic!("failed checkout should not be retried"),
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git_commands, fake_config())?;
+
+    // Act
+    let error = checkout(&context.git, &context.store, fake_checkout_args()).unwrap_err();
+
+    // Assert
+    assert!(error.to_string().contains("invalid reference: main"));
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn parent_context_without_ticket_or_scope_is_none() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock::fake();
+    let context = fake_context(git_commands.clone(), fake_config())?;
+
+    let parent = Branch::new(
+        &git_commands.branch_name.clone().unwrap(),
+        &git_commands.repo.clone().unwrap(),
+        None,
+        None,
+        None,
+    );
+    context.store.persist_branch(&parent)?;
+
+    // Act
+    let parent = parent_context(&context.git, &context.store);
+
+    // Assert
+    assert_eq!(None, parent);
+
+    context.close()?;
+
+    Ok(())
+}
+
 pub fn fake_checkout_args() -> Checkout {
     Checkout {
         name: Faker.fake(),
-        ticket: Some(Faker.fake()),
-        link: Some(Faker.fake()),
+        tickets: vec![Faker.fake()],
+        links: vec![Faker.fake()],
         scope: Some(Faker.fake()),
+        parent: None,
+        from: None,
+        track: false,
     }
 }
+
+/// Resolve the checkout status ahead of the handler in the same way as the cli.
+fn checkout<G: Git, S: Store>(git: &G, store: &S, args: Checkout) -> Result<Branch, Errors> {
+    let status = status(git, &args.name, args.from.as_deref(), args.track)?;
+    handler(git, store, args, status)
+}
@@ -0,0 +1,134 @@
+mod fakers;
+
+use std::collections::HashMap;
+
+use crate::fakers::{fake_commit, fake_config, fake_context, fake_template, GitCommandMock};
+use git_kit::{
+    domain::{
+        adapters::{Git, Store},
+        commands::commit_log::{handler, LogFilter},
+        models::Branch,
+    },
+    template_config::CommitConfig,
+};
+
+fn fake_git() -> GitCommandMock {
+    GitCommandMock {
+        log: |_| {
+            Ok(vec![
+                fake_commit("1111111aaa", "feat(cli): Add flag\n\nIssue: ABC-1"),
+                fake_commit("2222222bbb", "fix(cli): Fix crash\n\nIssue: ABC-2"),
+                fake_commit("3333333ccc", "feat(parser): Parse\n\nIssue: ABC-1"),
+                fake_commit("4444444ddd", "Unrelated message"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    }
+}
+
+#[test]
+fn log_decorates_commits_with_parsed_values_and_links() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(fake_git(), fake_config())?;
+
+    let branch = Branch::new(
+        &context.git.branch_name()?,
+        &context.git.repository_name()?,
+        Some("ABC-1".into()),
+        Some("https://issues/ABC-1".into()),
+        None,
+    );
+    context.store.persist_branch(&branch)?;
+
+    // Act
+    let entries = handler(
+        &context.git,
+        &context.store,
+        &fake_commit_config(),
+        "HEAD",
+        &LogFilter::default(),
+        10,
+    )?;
+
+    // Assert
+    assert_eq!(4, entries.len());
+    assert_eq!(Some("feat".into()), entries[0].template);
+    assert_eq!(Some("cli".into()), entries[0].scope);
+    assert_eq!(Some("ABC-1".into()), entries[0].ticket);
+    assert_eq!(Some("https://issues/ABC-1".into()), entries[0].link);
+    assert_eq!("Add flag", entries[0].message);
+
+    assert_eq!(None, entries[1].link);
+
+    assert_eq!(None, entries[3].template);
+    assert_eq!("Unrelated message", entries[3].message);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn log_filters_by_type_scope_and_ticket_before_limit() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(fake_git(), fake_config())?;
+    let config = fake_commit_config();
+
+    let hashes = |filter: LogFilter, limit: usize| -> anyhow::Result<Vec<String>> {
+        Ok(handler(
+            &context.git,
+            &context.store,
+            &config,
+            "HEAD",
+            &filter,
+            limit,
+        )?
+        .into_iter()
+        .map(|e| e.hash)
+        .collect())
+    };
+
+    // Act & Assert
+    let by_type = LogFilter {
+        template: Some("feat".into()),
+        ..LogFilter::default()
+    };
+    assert_eq!(
+        vec!["1111111aaa", "3333333ccc"],
+        hashes(by_type.clone(), 10)?
+    );
+    assert_eq!(vec!["1111111aaa"], hashes(by_type, 1)?);
+
+    let by_scope = LogFilter {
+        scope: Some("cli".into()),
+        ..LogFilter::default()
+    };
+    assert_eq!(vec!["1111111aaa", "2222222bbb"], hashes(by_scope, 10)?);
+
+    let by_ticket = LogFilter {
+        template: Some("feat".into()),
+        ticket: Some("abc-1".into()),
+        scope: Some("parser".into()),
+    };
+    assert_eq!(vec!["3333333ccc"], hashes(by_ticket, 10)?);
+
+    context.close()?;
+
+    Ok(())
+}
+
+fn fake_commit_config() -> CommitConfig {
+    CommitConfig {
+        templates: HashMap::from([
+            (
+                "feat".into(),
+                fake_template("feat({scope}): {message}\n\nIssue: {ticket_num} {link}"),
+            ),
+            (
+                "fix".into(),
+                fake_template("fix({scope}): {message}\n\nIssue: {ticket_num} {link}"),
+            ),
+        ]),
+        ..CommitConfig::default()
+    }
+}
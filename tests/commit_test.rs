@@ -5,9 +5,10 @@ use fake::{Fake, Faker};
 use git_kit::{
     adapters::sqlite::Sqlite,
     domain::{
-        adapters::{CommitMsgStatus, Git, Store},
-        commands::commit::{handler, Commit},
-        models::Branch,
+        adapters::{CommitMsgStatus, Git, GitOperation, Store},
+        commands::commit::{handler, preflight, retry, Commit},
+        errors::{Errors, GitError},
+        models::{Branch, CommitHistory, CommitInputs, CommitStatus},
     },
     template_config::Template,
 };
@@ -74,7 +75,12 @@ fn commit_message_with_no_args_or_stored_branch_defaults_correctly() -> anyhow::
         ticket: None,
         message: None,
         scope: None,
+        template_name: Faker.fake(),
         template: template_config,
+        body: None,
+        footers: vec![],
+        ticket_link: None,
+        ticket_separator: None,
     };
 
     // Act
@@ -99,17 +105,18 @@ fn commit_message_with_no_commit_args_defaults_to_stored_branch_values() -> anyh
         message: Some(Faker.fake()),
         ticket: None,
         scope: None,
+        ..fake_commit_args()
     };
 
     let context = fake_context(GitCommandMock::fake(), fake_config())?;
 
-    let branch_name = Some(context.git.branch_name()?);
+    let branch_name = context.git.branch_name()?;
     let repo_name = Some(context.git.repository_name()?);
     let ticket = None;
     let branch = Branch {
         link: Some(Faker.fake()),
         scope: Some(Faker.fake()),
-        ..fake_branch(branch_name.clone(), repo_name, ticket)?
+        ..fake_branch(Some(branch_name.clone()), repo_name, ticket)?
     };
 
     setup_db(&context.store, Some(&branch))?;
@@ -121,7 +128,7 @@ fn commit_message_with_no_commit_args_defaults_to_stored_branch_values() -> anyh
     // Assert
     let expected = format!(
         "[{}] message: '{}', scope: '{}', link: '{}'",
-        branch_name.unwrap(),
+        branch_name,
         args.message.unwrap(),
         branch.scope.unwrap(),
         branch.link.unwrap()
@@ -134,9 +141,186 @@ fn commit_message_with_no_commit_args_defaults_to_stored_branch_values() -> anyh
     Ok(())
 }
 
+#[test]
+fn commit_persists_rendered_message_as_committed_history() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+    let args = fake_commit_args();
+
+    // Act
+    let contents = handler(&context.git, &context.store, args.clone())?;
+
+    // Assert
+    let history = context.store.get_history(
+        &context.git.branch_name()?,
+        &context.git.repository_name()?,
+        10,
+    )?;
+
+    assert_eq!(1, history.len());
+    assert_eq!(contents, history[0].message);
+    assert_eq!(args.template_name, history[0].template);
+    assert_eq!(args.inputs(), history[0].inputs);
+    assert_eq!(CommitStatus::Committed, history[0].status);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn failed_commit_persists_rendered_message_as_failed_history() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        commit_res: |_, _| Err(GitError::Commit),
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+
+    // Act
+    let result = handler(&context.git, &context.store, fake_commit_args());
+
+    // Assert
+    assert!(result.is_err());
+
+    let history = context.store.get_history(
+        &context.git.branch_name()?,
+        &context.git.repository_name()?,
+        10,
+    )?;
+
+    assert_eq!(1, history.len());
+    assert_eq!(CommitStatus::Failed, history[0].status);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn retry_commits_with_stored_message_and_updates_status() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        commit_res: |_, complete| {
+            assert_eq!(CommitMsgStatus::Completed, complete);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+
+    let history = CommitHistory {
+        status: CommitStatus::Failed,
+        ..CommitHistory::new(
+            &context.git.repository_name()?,
+            &context.git.branch_name()?,
+            "bug",
+            CommitInputs::default(),
+            "subject\n\nbody",
+        )
+    };
+    context.store.persist_history(&history)?;
+
+    // Act
+    let contents = retry(&context.git, &context.store, history.clone())?;
+
+    // Assert
+    assert_eq!(history.message, contents);
+
+    let actual = context
+        .store
+        .get_history(&history.branch, &history.repo, 10)?;
+
+    assert_eq!(1, actual.len());
+    assert_eq!(CommitStatus::Committed, actual[0].status);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn preflight_with_staged_changes_succeeds() -> anyhow::Result<()> {
+    let git_mock = GitCommandMock {
+        staged_files: || Ok(vec![Faker.fake()]),
+        ..GitCommandMock::fake()
+    };
+
+    preflight(&git_mock, false)?;
+
+    Ok(())
+}
+
+#[test]
+fn preflight_with_nothing_staged_errors() {
+    let git_mock = GitCommandMock {
+        staged_files: || Ok(vec![]),
+        ..GitCommandMock::fake()
+    };
+
+    let error = preflight(&git_mock, false).unwrap_err();
+
+    assert!(matches!(error, Errors::Git(GitError::NothingStaged)));
+}
+
+#[test]
+fn preflight_with_operation_in_progress_errors() {
+    let git_mock = GitCommandMock {
+        staged_files: || Ok(vec![Faker.fake()]),
+        operation_in_progress: || Ok(Some(GitOperation::Merge)),
+        ..GitCommandMock::fake()
+    };
+
+    let error = preflight(&git_mock, false).unwrap_err();
+
+    assert!(matches!(
+        error,
+        Errors::Git(GitError::InProgress {
+            operation: GitOperation::Merge
+        })
+    ));
+    assert_eq!(
+        error.to_string(),
+        "A merge is in progress, please complete or abort the merge first"
+    );
+}
+
+#[test]
+fn preflight_staging_all_with_operation_in_progress_errors_before_staging() {
+    let git_mock = GitCommandMock {
+        operation_in_progress: || Ok(Some(GitOperation::Rebase)),
+        ..GitCommandMock::fake()
+    };
+
+    // Staging would panic within the mock, marking any conflicts as resolved.
+    let error = preflight(&git_mock, true).unwrap_err();
+
+    assert!(matches!(
+        error,
+        Errors::Git(GitError::InProgress {
+            operation: GitOperation::Rebase
+        })
+    ));
+}
+
+#[test]
+fn preflight_with_detached_head_errors() {
+    let git_mock = GitCommandMock {
+        staged_files: || Ok(vec![Faker.fake()]),
+        is_detached: || Ok(true),
+        ..GitCommandMock::fake()
+    };
+
+    let error = preflight(&git_mock, false).unwrap_err();
+
+    assert!(matches!(error, Errors::Git(GitError::DetachedHead)));
+}
+
 fn setup_db(store: &Sqlite, branch: Option<&Branch>) -> anyhow::Result<()> {
     if let Some(branch) = branch {
-        store.persist_branch(branch.into())?;
+        store.persist_branch(branch)?;
     }
 
     Ok(())
@@ -146,10 +330,15 @@ fn fake_commit_args() -> Commit {
     let template = fake_template();
 
     Commit {
+        template_name: Faker.fake(),
         template,
         ticket: Faker.fake(),
         message: Faker.fake(),
         scope: Faker.fake(),
+        body: Faker.fake(),
+        footers: vec![],
+        ticket_link: None,
+        ticket_separator: None,
     }
 }
 
@@ -157,6 +346,7 @@ fn fake_template() -> Template {
     Template {
         description: Faker.fake(),
         content: "[{ticket_num}] message: '{message}', scope: '{scope}', link: '{link}'".into(),
+        footers: vec![],
     }
 }
 
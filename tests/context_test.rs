@@ -1,10 +1,13 @@
 mod fakers;
 
+use std::collections::BTreeMap;
+
 use fake::{Fake, Faker};
 use git_kit::domain::{
     adapters::Store,
-    commands::context::{handler, Context},
-    models::Branch,
+    commands::context::{handler, set, unset, Context},
+    errors::Errors,
+    models::{Branch, Ticket},
 };
 
 use crate::fakers::{fake_branch, fake_config, fake_context, GitCommandMock};
@@ -15,7 +18,7 @@ fn current_success() -> anyhow::Result<()> {
     let branch_name = Faker.fake::<String>();
     let repo = Faker.fake::<String>();
     let command = Context {
-        ticket: Some(Faker.fake()),
+        tickets: vec![Faker.fake()],
         ..fake_context_args()
     };
 
@@ -40,8 +43,8 @@ fn current_success() -> anyhow::Result<()> {
 
     let expected = Branch {
         name,
-        ticket: command.ticket.unwrap(),
-        link: command.link,
+        ticket: command.tickets[0].clone(),
+        link: command.links.first().cloned(),
         scope: command.scope,
         ..branch.clone()
     };
@@ -56,7 +59,7 @@ fn current_success() -> anyhow::Result<()> {
 fn context_with_optionals_none_does_not_overwrite_db() -> anyhow::Result<()> {
     // Arrange
     let branch = Branch {
-        data: Some(Faker.fake()),
+        data: BTreeMap::from([("reviewer".into(), Faker.fake())]),
         link: Some(Faker.fake()),
         scope: Some(Faker.fake()),
         ..fake_branch()
@@ -74,9 +77,9 @@ fn context_with_optionals_none_does_not_overwrite_db() -> anyhow::Result<()> {
     context.store.persist_branch(&branch)?;
 
     let command = Context {
-        ticket: None,
+        tickets: vec![],
         scope: None,
-        link: None,
+        links: vec![],
     };
 
     // Act
@@ -98,10 +101,113 @@ fn context_with_optionals_none_does_not_overwrite_db() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn context_with_several_tickets_stores_additional_tickets() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock::fake();
+    let context = fake_context(git_commands.clone(), fake_config())?;
+
+    let command = Context {
+        tickets: vec!["ABC-1".into(), "ABC-2".into()],
+        scope: None,
+        links: vec!["https://issues/ABC-1".into(), "https://issues/ABC-2".into()],
+    };
+
+    // Act
+    handler(&context.git, &context.store, command)?;
+
+    // Assert
+    let branch = context.store.get_branch(
+        &git_commands.branch_name.unwrap(),
+        &git_commands.repo.unwrap(),
+    )?;
+
+    assert_eq!("ABC-1", branch.ticket);
+    assert_eq!(Some("https://issues/ABC-1".into()), branch.link);
+    assert_eq!(
+        vec![Ticket {
+            ticket: "ABC-2".into(),
+            link: Some("https://issues/ABC-2".into()),
+        }],
+        branch.tickets
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn context_set_and_unset_key_values() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock::fake();
+    let context = fake_context(git_commands.clone(), fake_config())?;
+    let (branch_name, repo) = (
+        git_commands.branch_name.unwrap(),
+        git_commands.repo.unwrap(),
+    );
+
+    // Act
+    set(&context.git, &context.store, "reviewer", "alice")?;
+    set(&context.git, &context.store, "pair", "bob")?;
+    let removed = unset(&context.git, &context.store, "pair")?;
+    let missing = unset(&context.git, &context.store, "pair")?;
+
+    // Assert
+    let branch = context.store.get_branch(&branch_name, &repo)?;
+
+    assert!(removed);
+    assert!(!missing);
+    assert_eq!(
+        BTreeMap::from([("reviewer".to_string(), "alice".to_string())]),
+        branch.data
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn context_set_rejects_reserved_or_invalid_keys() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+
+    for key in ["ticket_num", "message", "has space", "{brace}", ""] {
+        // Act
+        let result = set(&context.git, &context.store, key, "value");
+
+        // Assert
+        assert!(
+            matches!(result, Err(Errors::ValidationError { .. })),
+            "expected '{key}' to be rejected"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn context_update_keeps_key_values() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock::fake();
+    let context = fake_context(git_commands.clone(), fake_config())?;
+
+    set(&context.git, &context.store, "reviewer", "alice")?;
+
+    // Act
+    let branch = handler(&context.git, &context.store, fake_context_args())?;
+
+    // Assert
+    assert_eq!(Some(&"alice".to_string()), branch.data.get("reviewer"));
+
+    Ok(())
+}
+
 fn fake_context_args() -> Context {
     Context {
-        ticket: Faker.fake(),
+        tickets: Faker.fake(),
         scope: Faker.fake(),
-        link: Faker.fake(),
+        links: Faker.fake(),
     }
 }
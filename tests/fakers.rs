@@ -6,12 +6,15 @@ use git_kit::{
     adapters::sqlite::Sqlite,
     app_context::AppContext,
     domain::{
-        adapters::{CheckoutStatus, CommitMsgStatus, Git},
+        adapters::{
+            BranchLocation, CheckoutStatus, CommitMsgStatus, Git, GitOperation, MergeStrategy,
+        },
         errors::GitError,
-        models::{path::AbsolutePath, Branch, Config, ConfigStatus},
+        models::{path::AbsolutePath, Branch, Config, ConfigStatus, GitCommit},
     },
     entry::Interactive,
     migrations::{db_migrations, MigrationContext},
+    template_config::Template,
 };
 use rusqlite::Connection;
 
@@ -29,6 +32,7 @@ pub fn valid_template_file_path() -> AbsolutePath {
     path.try_into().unwrap()
 }
 
+#[allow(dead_code)]
 pub fn fake_config() -> Config {
     Config {
         key: Faker.fake::<String>().as_str().into(),
@@ -37,14 +41,15 @@ pub fn fake_config() -> Config {
     }
 }
 
-pub fn fake_context<'a, C: Git>(git: C, config: Config) -> anyhow::Result<AppContext<C, Sqlite>> {
+#[allow(dead_code)]
+pub fn fake_context<C: Git>(git: C, config: Config) -> anyhow::Result<AppContext<C, Sqlite>> {
     let mut connection = Connection::open_in_memory()?;
 
     db_migrations(
         &mut connection,
         MigrationContext {
             default_configs: None,
-            version: 4,
+            version: 9,
         },
     )?;
 
@@ -62,21 +67,48 @@ pub fn fake_context<'a, C: Git>(git: C, config: Config) -> anyhow::Result<AppCon
 pub fn fake_branch() -> Branch {
     Branch {
         name: Faker.fake(),
+        repo: Faker.fake(),
         ticket: Faker.fake(),
         created: Utc::now(),
         data: Faker.fake(),
         link: Faker.fake(),
         scope: Faker.fake(),
+        tickets: vec![],
+        last_used: None,
+        archived: None,
     }
 }
 
+type RewordFn = fn(&[(String, String)]) -> Result<(), GitError>;
+type RenamesFn = fn() -> Result<Vec<(String, String)>, GitError>;
+type LocationFn = fn(&str) -> Result<Option<BranchLocation>, GitError>;
+type MergeFn = fn(&str, &str, MergeStrategy) -> Result<(), GitError>;
+
 #[derive(Clone)]
 pub struct GitCommandMock {
     pub repo: Result<String, String>,
     pub branch_name: Result<String, String>,
     pub checkout_res: fn(&str, CheckoutStatus) -> Result<(), GitError>,
+    pub branch_location: LocationFn,
     pub commit_res: fn(&Path, CommitMsgStatus) -> Result<(), GitError>,
     pub template_file_path: fn() -> Result<PathBuf, GitError>,
+    pub staged_files: fn() -> Result<Vec<String>, GitError>,
+    pub operation_in_progress: fn() -> Result<Option<GitOperation>, GitError>,
+    pub is_detached: fn() -> Result<bool, GitError>,
+    pub log: fn(&str) -> Result<Vec<GitCommit>, GitError>,
+    pub is_ancestor: fn(&str) -> Result<bool, GitError>,
+    pub merges: fn(&str) -> Result<Vec<String>, GitError>,
+    pub show: fn(&str) -> Result<GitCommit, GitError>,
+    pub amend_message: fn(&str) -> Result<(), GitError>,
+    pub reword: RewordFn,
+    pub merge_base: fn(&str) -> Result<String, GitError>,
+    pub tags: fn() -> Result<Vec<String>, GitError>,
+    pub create_tag: fn(&str, &str) -> Result<(), GitError>,
+    pub rename_branch: fn(&str, &str) -> Result<(), GitError>,
+    pub branches: fn() -> Result<Vec<String>, GitError>,
+    pub branch_renames: RenamesFn,
+    pub merge: MergeFn,
+    pub delete_branch: fn(&str, bool) -> Result<(), GitError>,
 }
 
 impl GitCommandMock {
@@ -85,8 +117,26 @@ impl GitCommandMock {
             repo: Ok(Faker.fake()),
             branch_name: Ok(Faker.fake()),
             checkout_res: |_, _| Ok(()),
+            branch_location: |_| Ok(None),
             commit_res: |_, _| Ok(()),
             template_file_path: || Ok(VALID_FILE_PATH.clone().into()),
+            staged_files: || Ok(vec![]),
+            operation_in_progress: || Ok(None),
+            is_detached: || Ok(false),
+            log: |_| Ok(vec![]),
+            is_ancestor: |_| Ok(true),
+            merges: |_| Ok(vec![]),
+            show: |rev| Err(GitError::Revision { rev: rev.into() }),
+            amend_message: |_| panic!("Did not expect Git 'amend_message' to be called."),
+            reword: |_| panic!("Did not expect Git 'reword' to be called."),
+            merge_base: |base| Err(GitError::MergeBase { base: base.into() }),
+            tags: || Ok(vec![]),
+            create_tag: |_, _| Ok(()),
+            rename_branch: |_, _| Ok(()),
+            branches: || Ok(vec![]),
+            branch_renames: || Ok(vec![]),
+            merge: |_, _, _| Ok(()),
+            delete_branch: |_, _| Ok(()),
         }
     }
 }
@@ -110,10 +160,94 @@ impl Git for GitCommandMock {
         (self.checkout_res)(name, status)
     }
 
+    fn branch_location(&self, name: &str) -> Result<Option<BranchLocation>, GitError> {
+        (self.branch_location)(name)
+    }
+
     fn root_directory(&self) -> Result<AbsolutePath, GitError> {
         panic!("Did not expect Git 'root_directory' to be called.");
     }
 
+    fn rename_branch(&self, from: &str, to: &str) -> Result<(), GitError> {
+        (self.rename_branch)(from, to)
+    }
+
+    fn branches(&self) -> Result<Vec<String>, GitError> {
+        (self.branches)()
+    }
+
+    fn branch_renames(&self) -> Result<Vec<(String, String)>, GitError> {
+        (self.branch_renames)()
+    }
+
+    fn merge(&self, branch: &str, message: &str, strategy: MergeStrategy) -> Result<(), GitError> {
+        (self.merge)(branch, message, strategy)
+    }
+
+    fn delete_branch(&self, name: &str, force: bool) -> Result<(), GitError> {
+        (self.delete_branch)(name, force)
+    }
+
+    fn staged_files(&self) -> Result<Vec<String>, GitError> {
+        (self.staged_files)()
+    }
+
+    fn stage_tracked(&self) -> Result<(), GitError> {
+        panic!("Did not expect Git 'stage_tracked' to be called.");
+    }
+
+    fn operation_in_progress(&self) -> Result<Option<GitOperation>, GitError> {
+        (self.operation_in_progress)()
+    }
+
+    fn is_detached(&self) -> Result<bool, GitError> {
+        (self.is_detached)()
+    }
+
+    fn log(&self, range: &str, max_count: Option<usize>) -> Result<Vec<GitCommit>, GitError> {
+        let commits = (self.log)(range)?;
+        Ok(commits
+            .into_iter()
+            .take(max_count.unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    fn is_ancestor(&self, rev: &str) -> Result<bool, GitError> {
+        (self.is_ancestor)(rev)
+    }
+
+    fn merges(&self, range: &str) -> Result<Vec<String>, GitError> {
+        (self.merges)(range)
+    }
+
+    fn show(&self, rev: &str) -> Result<GitCommit, GitError> {
+        (self.show)(rev)
+    }
+
+    fn amend_message(&self, message: &str) -> Result<(), GitError> {
+        (self.amend_message)(message)
+    }
+
+    fn reword(&self, messages: &[(String, String)]) -> Result<(), GitError> {
+        (self.reword)(messages)
+    }
+
+    fn merge_base(&self, base: &str) -> Result<String, GitError> {
+        (self.merge_base)(base)
+    }
+
+    fn default_branch(&self) -> Result<String, GitError> {
+        Ok("main".into())
+    }
+
+    fn tags(&self) -> Result<Vec<String>, GitError> {
+        (self.tags)()
+    }
+
+    fn create_tag(&self, name: &str, message: &str) -> Result<(), GitError> {
+        (self.create_tag)(name, message)
+    }
+
     fn template_file_path(&self) -> Result<PathBuf, GitError> {
         (self.template_file_path)()
     }
@@ -126,3 +260,22 @@ impl Git for GitCommandMock {
         (self.commit_res)(template, complete)
     }
 }
+
+#[allow(dead_code)]
+pub fn fake_commit(hash: &str, message: &str) -> GitCommit {
+    GitCommit {
+        hash: hash.into(),
+        author: "author".into(),
+        date: Utc::now(),
+        message: message.into(),
+    }
+}
+
+#[allow(dead_code)]
+pub fn fake_template(content: &str) -> Template {
+    Template {
+        description: "description".into(),
+        content: content.into(),
+        footers: vec![],
+    }
+}
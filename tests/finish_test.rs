@@ -0,0 +1,254 @@
+mod fakers;
+
+use crate::fakers::{fake_commit, fake_config, fake_context, GitCommandMock};
+use git_kit::{
+    domain::{
+        adapters::{CheckoutStatus, Git, MergeStrategy, Store},
+        commands::{
+            finish::{handler, Finish},
+            message::{render, MessageValues},
+        },
+        errors::{Errors, GitError},
+        models::Branch,
+    },
+    template_config::{MessageTemplate, TemplateConfig},
+};
+
+#[test]
+fn finish_merges_into_base_with_rendered_message_and_archives_context() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branch_name: Ok("feat/login".into()),
+        log: |range| {
+            assert_eq!("main..HEAD", range);
+            Ok(vec![
+                fake_commit("b", "Add form\n\nBody"),
+                fake_commit("a", "Add route"),
+            ])
+        },
+        checkout_res: |name, status| {
+            assert_eq!("main", name);
+            assert_eq!(CheckoutStatus::Existing, status);
+            Ok(())
+        },
+        merge: |branch, message, strategy| {
+            assert_eq!("feat/login", branch);
+            assert_eq!(MergeStrategy::Merge, strategy);
+            assert!(message.starts_with("[ABC-1]"));
+            Ok(())
+        },
+        delete_branch: |_, _| panic!("branch should be kept"),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    context.store.persist_branch(&Branch::new(
+        "feat/login",
+        &repo,
+        Some("ABC-1".into()),
+        None,
+        None,
+    ))?;
+
+    // Act
+    let finished = handler(
+        &context.git,
+        &context.store,
+        &TemplateConfig::default(),
+        fake_finish(MergeStrategy::Merge, false),
+    )?;
+
+    // Assert
+    assert_eq!(
+        "[ABC-1] Merge 'feat/login' into main\n\n- Add route\n- Add form",
+        finished.message
+    );
+    assert!(finished.context.is_some());
+
+    let branch = context.store.get_branch("feat/login", &repo)?;
+    assert!(branch.archived.is_some());
+    assert_eq!("ABC-1", branch.ticket);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn finish_squash_force_deletes_branch() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        log: |_| Ok(vec![fake_commit("a", "Add route")]),
+        merge: |_, _, strategy| {
+            assert_eq!(MergeStrategy::Squash, strategy);
+            Ok(())
+        },
+        delete_branch: |_, force| {
+            assert!(force);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+
+    // Act
+    let finished = handler(
+        &context.git,
+        &context.store,
+        &TemplateConfig::default(),
+        fake_finish(MergeStrategy::Squash, true),
+    )?;
+
+    // Assert
+    assert!(finished.context.is_none());
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn finish_on_base_branch_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branch_name: Ok("main".into()),
+        merge: |_, _, _| panic!("merge should not be called"),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+
+    // Act
+    let result = handler(
+        &context.git,
+        &context.store,
+        &TemplateConfig::default(),
+        fake_finish(MergeStrategy::Merge, false),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn finish_without_commits_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        checkout_res: |_, _| panic!("base should not be checked out"),
+        merge: |_, _, _| panic!("merge should not be called"),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+
+    // Act
+    let result = handler(
+        &context.git,
+        &context.store,
+        &TemplateConfig::default(),
+        fake_finish(MergeStrategy::Merge, false),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn finish_on_merge_failure_keeps_context_and_branch() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        log: |_| Ok(vec![fake_commit("a", "Add route")]),
+        merge: |branch, _, _| {
+            Err(GitError::Merge {
+                branch: branch.into(),
+            })
+        },
+        delete_branch: |_, _| panic!("branch should not be deleted"),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    let name = context.git.branch_name()?;
+    context
+        .store
+        .persist_branch(&Branch::new(&name, &repo, None, None, None))?;
+
+    // Act
+    let result = handler(
+        &context.git,
+        &context.store,
+        &TemplateConfig::default(),
+        fake_finish(MergeStrategy::Merge, true),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::Git(GitError::Merge { .. }))));
+    assert!(context.store.get_branch(&name, &repo)?.archived.is_none());
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn message_without_context_removes_ticket_placeholder() -> anyhow::Result<()> {
+    let template = MessageTemplate {
+        content: "[{ticket_num}] ({scope}) Squash {source_branch}\n\n{commits}".into(),
+    };
+
+    let commits = [fake_commit("a", "Add route")];
+    let values = MessageValues {
+        source_branch: Some("feat/login"),
+        target_branch: Some("main"),
+        commits: &commits,
+        ..MessageValues::default()
+    };
+
+    let message = render(&template, values, None, &TemplateConfig::default().commit)?;
+
+    assert_eq!("Squash feat/login\n\n- Add route", message);
+
+    Ok(())
+}
+
+#[test]
+fn message_keeps_braces_within_commit_subjects() -> anyhow::Result<()> {
+    let template = MessageTemplate {
+        content: "Merge {source_branch} into {target_branch}\n\n{commits}".into(),
+    };
+
+    let commits = [
+        fake_commit("b", "Support {version} in {config}"),
+        fake_commit("a", "Add {ticket_num} placeholder"),
+    ];
+    let values = MessageValues {
+        source_branch: Some("feat/login"),
+        target_branch: Some("main"),
+        version: Some("1.2.0".into()),
+        commits: &commits,
+        ..MessageValues::default()
+    };
+
+    let message = render(&template, values, None, &TemplateConfig::default().commit)?;
+
+    assert_eq!(
+        "Merge feat/login into main\n\n- Add {ticket_num} placeholder\n- Support {version} in {config}",
+        message
+    );
+
+    Ok(())
+}
+
+fn fake_finish(strategy: MergeStrategy, delete: bool) -> Finish {
+    Finish {
+        base: "main".into(),
+        strategy,
+        delete,
+    }
+}
@@ -0,0 +1,129 @@
+mod fakers;
+
+use crate::fakers::{fake_commit, fake_config, fake_context, GitCommandMock};
+use git_kit::{
+    domain::{
+        adapters::{Git, Store},
+        commands::hook::prepare_commit_msg,
+        models::Branch,
+    },
+    template_config::TemplateConfig,
+};
+
+#[test]
+fn prepare_commit_msg_renders_merge_template_keeping_comments() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branch_name: Ok("main".into()),
+        log: |range| {
+            assert_eq!("HEAD..MERGE_HEAD", range);
+            Ok(vec![
+                fake_commit("abc1234def", "Add form"),
+                fake_commit("abc1234def", "Add route"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    context.store.persist_branch(&Branch::new(
+        "feat/login",
+        &repo,
+        Some("ABC-1".into()),
+        None,
+        None,
+    ))?;
+    let content = "Merge branch 'feat/login'\n\n# Conflicts:\n#\tsrc/main.rs\n";
+
+    // Act
+    let message = prepare_commit_msg(
+        &context.git,
+        &context.store,
+        &TemplateConfig::default(),
+        content,
+        Some("merge"),
+    )?;
+
+    // Assert
+    assert_eq!(
+        Some("[ABC-1] Merge 'feat/login' into main\n\n- Add route\n- Add form\n\n# Conflicts:\n#\tsrc/main.rs\n".into()),
+        message
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn prepare_commit_msg_remote_tracking_branch_uses_local_context() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branch_name: Ok("main".into()),
+        log: |_| Ok(vec![fake_commit("abc1234def", "Add route")]),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    context.store.persist_branch(&Branch::new(
+        "feat/login",
+        &repo,
+        Some("ABC-2".into()),
+        None,
+        None,
+    ))?;
+
+    // Act
+    let message = prepare_commit_msg(
+        &context.git,
+        &context.store,
+        &TemplateConfig::default(),
+        "Merge remote-tracking branch 'origin/feat/login' into main\n",
+        Some("merge"),
+    )?;
+
+    // Assert
+    assert_eq!(
+        Some("[ABC-2] Merge 'origin/feat/login' into main\n\n- Add route\n".into()),
+        message
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn prepare_commit_msg_leaves_other_messages_untouched() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        log: |_| panic!("commits should not be retrieved"),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let config = TemplateConfig::default();
+
+    // Act
+    let commit = prepare_commit_msg(
+        &context.git,
+        &context.store,
+        &config,
+        "Merge branch 'feat/login'\n",
+        Some("message"),
+    )?;
+    let custom = prepare_commit_msg(
+        &context.git,
+        &context.store,
+        &config,
+        "[ABC-1] Merge 'feat/login' into main\n",
+        Some("merge"),
+    )?;
+
+    // Assert
+    assert_eq!(None, commit);
+    assert_eq!(None, custom);
+
+    context.close()?;
+
+    Ok(())
+}
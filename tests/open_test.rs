@@ -0,0 +1,69 @@
+mod fakers;
+
+use crate::fakers::{fake_config, fake_context, GitCommandMock};
+use git_kit::domain::{
+    adapters::{Git, Store},
+    commands::open::handler,
+    errors::Errors,
+    models::Branch,
+};
+
+const PATTERN: &str = "https://tracker/browse/{ticket_num}";
+
+#[test]
+fn open_prefers_stored_link() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+    persist_branch(&context.git, &context.store, Some("https://stored/ABC-1"))?;
+
+    // Act
+    let link = handler(&context.git, &context.store, Some(PATTERN))?;
+
+    // Assert
+    assert_eq!("https://stored/ABC-1", link);
+
+    Ok(())
+}
+
+#[test]
+fn open_renders_link_from_ticket_link_pattern() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+    persist_branch(&context.git, &context.store, None)?;
+
+    // Act
+    let link = handler(&context.git, &context.store, Some(PATTERN))?;
+
+    // Assert
+    assert_eq!("https://tracker/browse/ABC-1", link);
+
+    Ok(())
+}
+
+#[test]
+fn open_without_link_or_pattern_errors() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+    persist_branch(&context.git, &context.store, None)?;
+
+    // Act
+    let result = handler(&context.git, &context.store, None);
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    Ok(())
+}
+
+fn persist_branch<G: Git, S: Store>(git: &G, store: &S, link: Option<&str>) -> anyhow::Result<()> {
+    let branch = Branch::new(
+        &git.branch_name()?,
+        &git.repository_name()?,
+        Some("ABC-1".into()),
+        link.map(String::from),
+        None,
+    );
+    store.persist_branch(&branch)?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+mod fakers;
+
+use std::collections::HashMap;
+
+use crate::fakers::{fake_commit, fake_config, fake_context, fake_template, GitCommandMock};
+use git_kit::{
+    domain::{
+        adapters::{Git, Store},
+        commands::release_notes::handler,
+        models::Branch,
+    },
+    template_config::CommitConfig,
+};
+
+#[test]
+fn release_notes_group_commits_by_ticket_with_links() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        log: |range| {
+            assert_eq!("v1.0.0..HEAD", range);
+            Ok(vec![
+                fake_commit("1111111aaa", "[ABC-1] ✨ Add flag"),
+                fake_commit("2222222bbb", "[ABC-2] 🐛 Fix crash"),
+                fake_commit("3333333ccc", "[ABC-1] 🐛 Fix flag"),
+                fake_commit("4444444ddd", "✨ Add docs for ABC-3"),
+                fake_commit("5555555eee", "Tidy up ABC-12"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+    let repo_name = context.git.repository_name()?;
+
+    for (name, ticket, link) in [
+        ("feature/one", "ABC-1", Some("https://issues/ABC-1")),
+        ("feature/three", "ABC-3", Some("https://issues/ABC-3")),
+    ] {
+        let branch = Branch::new(
+            name,
+            &repo_name,
+            Some(ticket.into()),
+            link.map(String::from),
+            None,
+        );
+        context.store.persist_branch(&branch)?;
+    }
+
+    let config = CommitConfig {
+        templates: HashMap::from([
+            ("feat".into(), fake_template("[{ticket_num}] ✨ {message}")),
+            ("bug".into(), fake_template("[{ticket_num}] 🐛 {message}")),
+        ]),
+        ..CommitConfig::default()
+    };
+
+    // Act
+    let notes = handler(&context.git, &context.store, &config, "v1.0.0..HEAD")?;
+
+    // Assert
+    let tickets = notes
+        .tickets
+        .iter()
+        .map(|t| (t.ticket.as_str(), t.link.as_deref(), t.commits.len()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            ("ABC-1", Some("https://issues/ABC-1"), 2),
+            ("ABC-2", None, 1),
+            ("ABC-3", Some("https://issues/ABC-3"), 1),
+        ],
+        tickets
+    );
+
+    assert_eq!("Add flag", notes.tickets[0].commits[0].message);
+    assert_eq!("Fix flag", notes.tickets[0].commits[1].message);
+
+    assert_eq!(1, notes.unticketed.len());
+    assert_eq!("Tidy up ABC-12", notes.unticketed[0].message);
+
+    context.close()?;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use git_kit::{adapters::Git, domain::adapters::GitSystem};
+
+/// Runs git within the given directory rather than the current directory shared by every test.
+pub struct GitIn(PathBuf);
+
+impl GitSystem for GitIn {
+    type Result = Command;
+
+    fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new("git");
+        command.current_dir(&self.0).envs(GIT_IDENTITY).args(args);
+        command
+    }
+}
+
+const GIT_IDENTITY: [(&str, &str); 4] = [
+    ("GIT_AUTHOR_NAME", "git-kit"),
+    ("GIT_AUTHOR_EMAIL", "git-kit@test"),
+    ("GIT_COMMITTER_NAME", "git-kit"),
+    ("GIT_COMMITTER_EMAIL", "git-kit@test"),
+];
+
+pub fn git_in(dir: &Path) -> Git<GitIn> {
+    Git {
+        git: GitIn(dir.into()),
+    }
+}
+
+/// Temporary directory removed once dropped.
+pub struct TempDir(PathBuf);
+
+impl std::ops::Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+pub fn temp_dir(name: &str) -> anyhow::Result<TempDir> {
+    let dir = std::env::temp_dir().join(format!("git-kit-test-{}-{}", std::process::id(), name));
+
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    std::fs::create_dir_all(&dir)?;
+
+    Ok(TempDir(dunce::canonicalize(dir)?))
+}
+
+pub fn init_repo(dir: &Path) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    git(dir, &["init", "--quiet", "--initial-branch", "main"])?;
+    git(
+        dir,
+        &["commit", "--quiet", "--allow-empty", "-m", "Initial"],
+    )?;
+
+    Ok(dir.into())
+}
+
+/// Run git within a directory returning its trimmed output.
+pub fn git(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .envs(GIT_IDENTITY)
+        .args(args)
+        .output()?;
+
+    anyhow::ensure!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    Ok(String::from_utf8(output.stdout)?.trim().into())
+}
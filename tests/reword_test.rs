@@ -0,0 +1,282 @@
+mod fakers;
+mod repos;
+
+use crate::{
+    fakers::{fake_commit, GitCommandMock},
+    repos::{git, git_in, init_repo, temp_dir},
+};
+use git_kit::{
+    domain::{
+        adapters::Git,
+        commands::{commit::Commit, reword::handler},
+        errors::Errors,
+    },
+    template_config::Template,
+};
+
+const HEAD: &str = "1111111aaa";
+
+#[test]
+fn reword_head_amends_message() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        show: |_| Ok(fake_commit(HEAD, "[ABC-1] 🐛 Fix")),
+        amend_message: |message| {
+            assert_eq!("[ABC-2] ✨ Fix", message);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let target = fake_commit(HEAD, "[ABC-1] 🐛 Fix");
+
+    // Act
+    let message = handler(
+        &git,
+        &target,
+        fake_args("[{ticket_num}] ✨ {message}"),
+        None,
+    )?;
+
+    // Assert
+    assert_eq!("[ABC-2] ✨ Fix", message);
+
+    Ok(())
+}
+
+#[test]
+fn reword_older_commit_rewrites_history() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        show: |_| Ok(fake_commit(HEAD, "Newer commit")),
+        reword: |messages| {
+            assert_eq!(
+                [(
+                    "2222222bbb".to_string(),
+                    "fix: Fix\n\nIssue: ABC-2 https://issues/ABC-2".to_string()
+                )],
+                messages
+            );
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let target = fake_commit("2222222bbb", "fix: Fix");
+
+    // Act
+    handler(
+        &git,
+        &target,
+        fake_args("fix({scope}): {message}\n\nIssue: {ticket_num} {link}"),
+        Some("https://issues/ABC-2".into()),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn reword_without_parsed_link_renders_link_from_ticket_link_pattern() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        show: |_| {
+            Ok(fake_commit(
+                HEAD,
+                "fix: Fix\n\nIssue: ABC-1 https://issues/ABC-1",
+            ))
+        },
+        amend_message: |_| Ok(()),
+        ..GitCommandMock::fake()
+    };
+
+    let target = fake_commit(HEAD, "fix: Fix\n\nIssue: ABC-1 https://issues/ABC-1");
+
+    let args = Commit {
+        ticket_link: Some("https://tracker/browse/{ticket_num}".into()),
+        ..fake_args("fix({scope}): {message}\n\nIssue: {ticket_num} {link}")
+    };
+
+    // Act
+    let message = handler(&git, &target, args, None)?;
+
+    // Assert
+    assert_eq!(
+        "fix: Fix\n\nIssue: ABC-2 https://tracker/browse/ABC-2",
+        message
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reword_keeps_body_when_template_has_no_body_placeholder() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        show: |_| Ok(fake_commit(HEAD, "")),
+        amend_message: |message| {
+            assert_eq!("[ABC-2] ✨ Fix\n\nSome details", message);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let target = fake_commit(HEAD, "🐛 Fix\n\nSome details");
+
+    let args = Commit {
+        body: Some("Some details".into()),
+        ..fake_args("[{ticket_num}] ✨ {message}")
+    };
+
+    // Act
+    handler(&git, &target, args, None)?;
+
+    Ok(())
+}
+
+#[test]
+fn reword_with_unchanged_message_does_nothing() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        show: |_| panic!("show should not be called"),
+        ..GitCommandMock::fake()
+    };
+
+    let target = fake_commit(HEAD, "[ABC-2] ✨ Fix\n");
+
+    // Act
+    handler(
+        &git,
+        &target,
+        fake_args("[{ticket_num}] ✨ {message}"),
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn reword_commit_not_on_current_branch_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        show: |_| Ok(fake_commit(HEAD, "Newer commit")),
+        is_ancestor: |rev| {
+            assert_eq!("2222222bbb", rev);
+            Ok(false)
+        },
+        ..GitCommandMock::fake()
+    };
+
+    let target = fake_commit("2222222bbb", "fix: Fix");
+
+    // Act
+    let result = handler(&git, &target, fake_args("[{ticket_num}] {message}"), None);
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn reword_commit_on_another_branch_keeps_history() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("reword-other-branch")?;
+    let repo = init_repo(&root.join("project"))?;
+    git(&repo, &["checkout", "--quiet", "-b", "other"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "Other fix"],
+    )?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "Other work"],
+    )?;
+    git(&repo, &["checkout", "--quiet", "-b", "feature", "main"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "Feature work"],
+    )?;
+    let head = git(&repo, &["rev-parse", "HEAD"])?;
+    let other = git(&repo, &["rev-parse", "other"])?;
+
+    let git_repo = git_in(&repo);
+    let target = git_repo.show("other~1")?;
+
+    // Act
+    let result = handler(
+        &git_repo,
+        &target,
+        fake_args("[{ticket_num}] {message}"),
+        None,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+    assert_eq!(head, git(&repo, &["rev-parse", "HEAD"])?);
+    assert_eq!(other, git(&repo, &["rev-parse", "other"])?);
+
+    Ok(())
+}
+
+#[test]
+fn reword_across_merge_commit_keeps_history() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("reword-merge")?;
+    let repo = init_repo(&root.join("project"))?;
+    git(&repo, &["checkout", "--quiet", "-b", "feature"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "First fix"],
+    )?;
+    git(&repo, &["checkout", "--quiet", "main"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "Main work"],
+    )?;
+    git(&repo, &["checkout", "--quiet", "feature"])?;
+    git(&repo, &["merge", "--quiet", "--no-edit", "main"])?;
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "Second fix"],
+    )?;
+    let head = git(&repo, &["rev-parse", "HEAD"])?;
+
+    let git_repo = git_in(&repo);
+    let target = git_repo.show("HEAD~1^1")?;
+
+    // Act
+    let result = handler(
+        &git_repo,
+        &target,
+        fake_args("[{ticket_num}] {message}"),
+        None,
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+    assert_eq!(head, git(&repo, &["rev-parse", "HEAD"])?);
+    assert_eq!(
+        "Merge branch 'main' into feature",
+        git(&repo, &["log", "--merges", "--format=%s", "HEAD"])?
+    );
+
+    Ok(())
+}
+
+fn fake_args(content: &str) -> Commit {
+    Commit {
+        template_name: "template".into(),
+        template: Template {
+            description: "description".into(),
+            content: content.into(),
+            footers: vec![],
+        },
+        ticket: Some("ABC-2".into()),
+        message: Some("Fix".into()),
+        scope: None,
+        body: None,
+        footers: vec![],
+        ticket_link: None,
+        ticket_separator: None,
+    }
+}
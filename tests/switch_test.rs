@@ -0,0 +1,126 @@
+mod fakers;
+
+use chrono::{Duration, Utc};
+
+use crate::fakers::{fake_config, fake_context, GitCommandMock};
+use git_kit::domain::{
+    adapters::{CheckoutStatus, Git, Store},
+    commands::switch::{branches, handler},
+    models::Branch,
+};
+
+#[test]
+fn branches_are_most_recently_used_first_excluding_current() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branch_name: Ok("main".into()),
+        branches: || {
+            Ok(vec![
+                "feat/a".into(),
+                "feat/b".into(),
+                "main".into(),
+                "feat/c".into(),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+
+    for (name, days) in [("feat/b", 2), ("feat/c", 1)] {
+        let branch = Branch {
+            last_used: Some(Utc::now() - Duration::days(days)),
+            ..Branch::new(name, &repo, Some(format!("ABC-{days}")), None, None)
+        };
+        context.store.persist_branch(&branch)?;
+    }
+
+    // Act
+    let branches = branches(&context.git, &context.store)?;
+
+    // Assert
+    let names: Vec<_> = branches.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(vec!["feat/c", "feat/b", "feat/a"], names);
+
+    let tickets: Vec<_> = branches
+        .iter()
+        .map(|b| b.context.as_ref().map(|branch| branch.ticket.as_str()))
+        .collect();
+    assert_eq!(vec![Some("ABC-1"), Some("ABC-2"), None], tickets);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn branches_when_detached_includes_all_local_branches() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        branch_name: Err("HEAD is detached".into()),
+        branches: || Ok(vec!["feat/a".into(), "main".into()]),
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+
+    // Act
+    let branches = branches(&context.git, &context.store)?;
+
+    // Assert
+    assert_eq!(2, branches.len());
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn switch_checks_out_existing_branch_and_records_last_used() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        checkout_res: |name, status| {
+            assert_eq!("feat/a", name);
+            assert_eq!(CheckoutStatus::Existing, status);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+    let context = fake_context(git, fake_config())?;
+    let repo = context.git.repository_name()?;
+    context
+        .store
+        .persist_branch(&Branch::new("feat/a", &repo, None, None, None))?;
+
+    // Act
+    let switched = handler(&context.git, &context.store, "feat/a")?;
+
+    // Assert
+    assert!(switched.is_some());
+    assert!(context
+        .store
+        .get_branch("feat/a", &repo)?
+        .last_used
+        .is_some());
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn switch_without_stored_context_only_checks_out() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+    let repo = context.git.repository_name()?;
+
+    // Act
+    let switched = handler(&context.git, &context.store, "feat/a")?;
+
+    // Assert
+    assert!(switched.is_none());
+    assert!(context.store.get_branch("feat/a", &repo).is_err());
+
+    context.close()?;
+
+    Ok(())
+}
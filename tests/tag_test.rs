@@ -0,0 +1,164 @@
+mod fakers;
+
+use std::collections::HashMap;
+
+use crate::fakers::{fake_commit, fake_template, GitCommandMock};
+use git_kit::{
+    domain::{
+        commands::tag::{handler, Tag},
+        errors::Errors,
+        models::Version,
+    },
+    template_config::{BumpConfig, CommitConfig, MessageTemplate, TemplateConfig},
+};
+
+#[test]
+fn tag_suggested_version_renders_template_with_commits_since_previous() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        tags: || Ok(vec!["v1.2.3".into()]),
+        log: |range| {
+            assert_eq!("v1.2.3..HEAD", range);
+            Ok(vec![
+                fake_commit("abc1234def", "feat(cli): Add tag"),
+                fake_commit("abc1234def", "fix: Handle empty log\n\nBody"),
+            ])
+        },
+        create_tag: |name, message| {
+            assert_eq!("v1.3.0", name);
+            assert_eq!(
+                "Release v1.3.0 since v1.2.3\n\n- fix: Handle empty log\n- feat(cli): Add tag",
+                message
+            );
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+    let config = TemplateConfig {
+        tag: MessageTemplate {
+            content: "Release {version} since {previous_version}\n\n{commits}".into(),
+        },
+        ..fake_config()
+    };
+
+    // Act
+    let tagged = handler(
+        &git,
+        &config,
+        Tag {
+            version: None,
+            message: None,
+        },
+    )?;
+
+    // Assert
+    assert_eq!(Version::parse("v1.3.0"), Some(tagged.version));
+    assert_eq!(Version::parse("v1.2.3"), tagged.previous);
+
+    Ok(())
+}
+
+#[test]
+fn tag_explicit_version_without_previous_version_lists_all_commits() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        log: |range| {
+            assert_eq!("HEAD", range);
+            Ok(vec![fake_commit("abc1234def", "chore: Initial commit")])
+        },
+        create_tag: |name, message| {
+            assert_eq!("v1.0.0", name);
+            assert_eq!("Release v1.0.0\n\n- chore: Initial commit", message);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+
+    // Act
+    let tagged = handler(
+        &git,
+        &fake_config(),
+        Tag {
+            version: Version::parse("v1.0.0"),
+            message: None,
+        },
+    )?;
+
+    // Assert
+    assert_eq!(None, tagged.previous);
+
+    Ok(())
+}
+
+#[test]
+fn tag_with_message_skips_template() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        log: |_| panic!("commits aren't needed for a given message"),
+        create_tag: |_, message| {
+            assert_eq!("Hotfix", message);
+            Ok(())
+        },
+        ..GitCommandMock::fake()
+    };
+
+    // Act
+    let tagged = handler(
+        &git,
+        &fake_config(),
+        Tag {
+            version: Version::parse("v1.0.1"),
+            message: Some("Hotfix".into()),
+        },
+    )?;
+
+    // Assert
+    assert_eq!("Hotfix", tagged.message);
+
+    Ok(())
+}
+
+#[test]
+fn tag_without_release_worthy_commits_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        tags: || Ok(vec!["v1.2.3".into()]),
+        log: |_| Ok(vec![fake_commit("abc1234def", "chore: Tidy")]),
+        create_tag: |_, _| panic!("tag should not be created"),
+        ..GitCommandMock::fake()
+    };
+
+    // Act
+    let result = handler(
+        &git,
+        &fake_config(),
+        Tag {
+            version: None,
+            message: None,
+        },
+    );
+
+    // Assert
+    assert!(matches!(result, Err(Errors::ValidationError { .. })));
+
+    Ok(())
+}
+
+fn fake_config() -> TemplateConfig {
+    TemplateConfig {
+        commit: CommitConfig {
+            templates: HashMap::from([
+                ("feat".into(), fake_template("feat({scope}): {message}")),
+                ("fix".into(), fake_template("fix({scope}): {message}")),
+                ("chore".into(), fake_template("chore({scope}): {message}")),
+            ]),
+            bumps: BumpConfig {
+                minor: vec!["feat".into()],
+                patch: vec!["fix".into()],
+                ..BumpConfig::default()
+            },
+            ..CommitConfig::default()
+        },
+        ..TemplateConfig::default()
+    }
+}
@@ -0,0 +1,130 @@
+mod fakers;
+
+use std::collections::HashMap;
+
+use crate::fakers::{fake_commit, fake_template, GitCommandMock};
+use git_kit::{
+    domain::{
+        commands::version::next,
+        models::{Bump, Version},
+    },
+    template_config::{BumpConfig, CommitConfig},
+};
+
+#[test]
+fn next_version_uses_most_significant_bump_since_latest_version_tag() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        tags: || Ok(vec!["latest".into(), "v1.2.3".into(), "v1.2.2".into()]),
+        log: |range| {
+            assert_eq!("v1.2.3..HEAD", range);
+            Ok(vec![
+                fake_commit("abc1234def", "fix: Fix crash"),
+                fake_commit("abc1234def", "feat: Add command"),
+                fake_commit("abc1234def", "chore: Tidy up"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    // Act
+    let suggestion = next(&git, &fake_config())?;
+
+    // Assert
+    assert_eq!(Some(Bump::Minor), suggestion.bump);
+    assert_eq!(Version::parse("v1.2.3"), suggestion.current);
+    assert_eq!(Version::parse("v1.3.0"), suggestion.next);
+    assert_eq!(3, suggestion.commits);
+
+    Ok(())
+}
+
+#[test]
+fn next_version_with_breaking_footer_is_major() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        tags: || Ok(vec!["v1.2.3".into()]),
+        log: |_| {
+            Ok(vec![
+                fake_commit(
+                    "abc1234def",
+                    "fix: Fix crash\n\nBREAKING CHANGE: removed flag",
+                ),
+                fake_commit("abc1234def", "feat: Add command"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    // Act
+    let suggestion = next(&git, &fake_config())?;
+
+    // Assert
+    assert_eq!(Some(Bump::Major), suggestion.bump);
+    assert_eq!(Version::parse("v2.0.0"), suggestion.next);
+
+    Ok(())
+}
+
+#[test]
+fn next_version_without_version_tag_starts_from_zero() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        tags: || Ok(vec![]),
+        log: |range| {
+            assert_eq!("HEAD", range);
+            Ok(vec![fake_commit("abc1234def", "fix: Fix crash")])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    // Act
+    let suggestion = next(&git, &fake_config())?;
+
+    // Assert
+    assert_eq!(None, suggestion.current);
+    assert_eq!(Version::parse("v0.0.1"), suggestion.next);
+
+    Ok(())
+}
+
+#[test]
+fn next_version_without_bump_rules_matching_is_none() -> anyhow::Result<()> {
+    // Arrange
+    let git = GitCommandMock {
+        tags: || Ok(vec!["v1.2.3".into()]),
+        log: |_| {
+            Ok(vec![
+                fake_commit("abc1234def", "chore: Tidy up"),
+                fake_commit("abc1234def", "Unrelated"),
+            ])
+        },
+        ..GitCommandMock::fake()
+    };
+
+    // Act
+    let suggestion = next(&git, &fake_config())?;
+
+    // Assert
+    assert_eq!(None, suggestion.bump);
+    assert_eq!(None, suggestion.next);
+    assert_eq!(2, suggestion.commits);
+
+    Ok(())
+}
+
+fn fake_config() -> CommitConfig {
+    CommitConfig {
+        templates: HashMap::from([
+            ("feat".into(), fake_template("feat({scope}): {message}")),
+            ("fix".into(), fake_template("fix({scope}): {message}")),
+            ("chore".into(), fake_template("chore({scope}): {message}")),
+        ]),
+        bumps: BumpConfig {
+            minor: vec!["feat".into()],
+            patch: vec!["fix".into()],
+            ..BumpConfig::default()
+        },
+        ..CommitConfig::default()
+    }
+}
@@ -0,0 +1,140 @@
+mod repos;
+
+use std::path::{Path, PathBuf};
+
+use crate::repos::{git, git_in, init_repo, temp_dir};
+use git_kit::{
+    adapters::sqlite::Sqlite,
+    domain::{
+        adapters::{Git, Store},
+        commands::context::{handler, Context},
+    },
+    migrations::{db_migrations, MigrationContext},
+};
+use rusqlite::Connection;
+
+#[test]
+fn worktree_template_file_path_is_within_worktree_git_dir() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("template")?;
+    let repo = init_repo(&root.join("project"))?;
+    let worktree = add_worktree(&repo, &root.join("project-feature"), "feature")?;
+
+    // Act
+    let path = git_in(&worktree).template_file_path()?;
+
+    // Assert
+    let expected = repo.join(".git/worktrees/project-feature/GIT_KIT_COMMIT_TEMPLATE");
+    assert_eq!(
+        dunce::canonicalize(expected.parent().unwrap())?,
+        path.parent().unwrap()
+    );
+    std::fs::write(&path, "message")?;
+
+    Ok(())
+}
+
+#[test]
+fn worktree_shares_repository_name_with_main_worktree() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("repository")?;
+    let repo = init_repo(&root.join("project"))?;
+    let worktree = add_worktree(&repo, &root.join("project-feature"), "feature")?;
+
+    // Act
+    let name = git_in(&worktree).repository_name()?;
+
+    // Assert
+    assert_eq!(repo.file_name().unwrap(), name.as_str());
+
+    Ok(())
+}
+
+#[test]
+fn worktree_context_is_visible_from_main_worktree() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("context")?;
+    let repo = init_repo(&root.join("project"))?;
+    let worktree = add_worktree(&repo, &root.join("project-feature"), "feature")?;
+    let store = fake_store()?;
+
+    let args = Context {
+        tickets: vec!["ABC-1".into()],
+        scope: None,
+        links: vec![],
+    };
+
+    // Act
+    handler(&git_in(&worktree), &store, args)?;
+
+    // Assert
+    let repo_name = repo.file_name().unwrap().to_str().unwrap();
+    let branch = store.get_branch("feature", repo_name)?;
+    assert_eq!(branch.ticket, "ABC-1");
+
+    Ok(())
+}
+
+#[test]
+fn submodule_resolves_own_git_dir_and_repository_name() -> anyhow::Result<()> {
+    // Arrange
+    let root = temp_dir("submodule")?;
+    let library = init_repo(&root.join("library"))?;
+    let repo = init_repo(&root.join("project"))?;
+
+    git(
+        &repo,
+        &[
+            "-c",
+            "protocol.file.allow=always",
+            "submodule",
+            "--quiet",
+            "add",
+            library.to_str().unwrap(),
+            "vendor/library",
+        ],
+    )?;
+    let submodule = repo.join("vendor/library");
+
+    // Act
+    let git = git_in(&submodule);
+    let name = git.repository_name()?;
+    let path = git.template_file_path()?;
+
+    // Assert
+    assert_eq!(name, "library");
+    let expected = dunce::canonicalize(repo.join(".git/modules/vendor/library"))?;
+    assert_eq!(expected.join("GIT_KIT_COMMIT_TEMPLATE"), path);
+
+    Ok(())
+}
+
+fn fake_store() -> anyhow::Result<Sqlite> {
+    let mut connection = Connection::open_in_memory()?;
+
+    db_migrations(
+        &mut connection,
+        MigrationContext {
+            default_configs: None,
+            version: 9,
+        },
+    )?;
+
+    Ok(Sqlite::new(connection))
+}
+
+fn add_worktree(repo: &Path, dir: &Path, branch: &str) -> anyhow::Result<PathBuf> {
+    git(
+        repo,
+        &[
+            "worktree",
+            "add",
+            "--quiet",
+            "-b",
+            branch,
+            dir.to_str().unwrap(),
+        ],
+    )?;
+
+    Ok(dir.into())
+}